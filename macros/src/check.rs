@@ -0,0 +1,91 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Data, DeriveInput, Expr, Lit, Meta, Token};
+
+/// Re-emits `item` with a never called function that coerces every `default` and
+/// `skip_serializing_if` path of its `#[serde]` attributes into the signature serde expects
+pub(crate) fn expand(item: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let this = quote!(#name #ty_generics);
+
+    let mut checks = Vec::new();
+    for path in serde_paths(&item.attrs, "default") {
+        checks.push(quote_spanned!(path.span=> let _: fn() -> #this = #path;));
+    }
+    let fields: Vec<&syn::Field> = match &item.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data.variants.iter().flat_map(|v| &v.fields).collect(),
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "check_defaults supports only structs and enums",
+            ))
+        }
+    };
+    for field in fields {
+        let ty = &field.ty;
+        for path in serde_paths(&field.attrs, "default") {
+            checks.push(quote_spanned!(path.span=> let _: fn() -> #ty = #path;));
+        }
+        for path in serde_paths(&field.attrs, "skip_serializing_if") {
+            checks.push(quote_spanned!(path.span=> let _: fn(&#ty) -> bool = #path;));
+        }
+    }
+
+    Ok(quote! {
+        #item
+
+        const _: () = {
+            #[allow(dead_code, clippy::all)]
+            fn check_defaults #impl_generics () #where_clause {
+                #(#checks)*
+            }
+        };
+    })
+}
+
+/// Function path given as `#[serde(key = "path")]`, spanned at the string
+pub(crate) struct SerdePath {
+    path: syn::ExprPath,
+    span: proc_macro2::Span,
+}
+
+impl quote::ToTokens for SerdePath {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens);
+    }
+}
+
+/// Items of every `#[serde(...)]` attribute, those serde can't parse are skipped here and left for serde to report
+pub(crate) fn serde_metas(attrs: &[Attribute]) -> impl Iterator<Item = Meta> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("serde"))
+        .filter_map(|attr| {
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .flatten()
+}
+
+pub(crate) fn serde_paths(attrs: &[Attribute], key: &str) -> Vec<SerdePath> {
+    serde_metas(attrs)
+        .filter_map(|meta| match meta {
+            Meta::NameValue(pair) if pair.path.is_ident(key) => match pair.value {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => Some(lit),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter_map(|lit| {
+            Some(SerdePath {
+                path: lit.parse().ok()?,
+                span: lit.span(),
+            })
+        })
+        .collect()
+}
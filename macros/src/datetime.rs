@@ -0,0 +1,105 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+#[cfg(feature = "chrono")]
+pub(crate) fn chrono_date(krate: &TokenStream, lit: &LitStr) -> syn::Result<TokenStream> {
+    use chrono::Datelike;
+
+    let date = chrono::NaiveDate::parse_from_str(&lit.value(), "%Y-%m-%d")
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid date: {e}")))?;
+    let (year, month, day) = (date.year(), date.month(), date.day());
+    Ok(quote! {
+        match #krate::__private::chrono::NaiveDate::from_ymd_opt(#year, #month, #day) {
+            ::core::option::Option::Some(date) => date,
+            ::core::option::Option::None => ::core::panic!("date validated at compile time"),
+        }
+    })
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn chrono_datetime(krate: &TokenStream, lit: &LitStr) -> syn::Result<TokenStream> {
+    let datetime = chrono::DateTime::parse_from_rfc3339(&lit.value())
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid RFC 3339 datetime: {e}")))?;
+    let secs = datetime.timestamp();
+    let nanos = datetime.timestamp_subsec_nanos();
+    Ok(quote! {
+        match #krate::__private::chrono::DateTime::<#krate::__private::chrono::Utc>::from_timestamp(#secs, #nanos) {
+            ::core::option::Option::Some(datetime) => datetime,
+            ::core::option::Option::None => ::core::panic!("datetime validated at compile time"),
+        }
+    })
+}
+
+#[cfg(feature = "time")]
+fn time_date_tokens(krate: &TokenStream, date: time::Date) -> TokenStream {
+    let year = date.year();
+    let month = syn::Ident::new(&date.month().to_string(), proc_macro2::Span::call_site());
+    let day = date.day();
+    quote! {
+        match #krate::__private::time::Date::from_calendar_date(#year, #krate::__private::time::Month::#month, #day) {
+            ::core::result::Result::Ok(date) => date,
+            ::core::result::Result::Err(_) => ::core::panic!("date validated at compile time"),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+fn time_time_tokens(krate: &TokenStream, time: time::Time) -> TokenStream {
+    let (hour, minute, second, nano) = time.as_hms_nano();
+    quote! {
+        match #krate::__private::time::Time::from_hms_nano(#hour, #minute, #second, #nano) {
+            ::core::result::Result::Ok(time) => time,
+            ::core::result::Result::Err(_) => ::core::panic!("time validated at compile time"),
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+pub(crate) fn time_date(krate: &TokenStream, lit: &LitStr) -> syn::Result<TokenStream> {
+    let format = time::format_description::parse_borrowed::<2>("[year]-[month]-[day]")
+        .expect("valid format");
+    let date = time::Date::parse(&lit.value(), &format)
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid date: {e}")))?;
+    Ok(time_date_tokens(krate, date))
+}
+
+#[cfg(feature = "time")]
+pub(crate) fn time_time(krate: &TokenStream, lit: &LitStr) -> syn::Result<TokenStream> {
+    let format = time::format_description::parse_owned::<2>(
+        "[hour]:[minute]:[second][optional [.[subsecond]]]",
+    )
+    .expect("valid format");
+    let time = time::Time::parse(&lit.value(), &format)
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid time: {e}")))?;
+    Ok(time_time_tokens(krate, time))
+}
+
+#[cfg(feature = "time")]
+pub(crate) fn time_datetime(krate: &TokenStream, lit: &LitStr) -> syn::Result<TokenStream> {
+    let datetime =
+        time::OffsetDateTime::parse(&lit.value(), &time::format_description::well_known::Rfc3339)
+            .map_err(|e| syn::Error::new(lit.span(), format!("invalid RFC 3339 datetime: {e}")))?;
+    let date = time_date_tokens(krate, datetime.date());
+    let time = time_time_tokens(krate, datetime.time());
+    let offset = datetime.offset().whole_seconds();
+    Ok(quote! {
+        #krate::__private::time::PrimitiveDateTime::new(#date, #time).assume_offset(
+            match #krate::__private::time::UtcOffset::from_whole_seconds(#offset) {
+                ::core::result::Result::Ok(offset) => offset,
+                ::core::result::Result::Err(_) => ::core::panic!("offset validated at compile time"),
+            },
+        )
+    })
+}
+
+#[cfg(feature = "jiff")]
+pub(crate) fn jiff_timestamp(krate: &TokenStream, lit: &LitStr) -> syn::Result<TokenStream> {
+    let timestamp: jiff::Timestamp = lit
+        .value()
+        .parse()
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid timestamp: {e}")))?;
+    let second = timestamp.as_second();
+    let nanosecond = timestamp.subsec_nanosecond();
+    Ok(quote!(#krate::__private::jiff::Timestamp::constant(#second, #nanosecond)))
+}
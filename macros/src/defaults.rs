@@ -0,0 +1,169 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{parse_quote, Data, DeriveInput, Fields};
+
+use crate::check::serde_metas;
+
+/// `consts = path`, `env_prefix = "..."` and `file = "..."` as passed to `#[serde_defaults(...)]`
+#[derive(Default)]
+pub(crate) struct Args {
+    consts: Option<syn::Path>,
+    /// Passed on to the derive as `#[serde_default(env_prefix = "...")]`
+    env_prefix: Option<syn::LitStr>,
+    /// `.toml` or `.json` file with a default for each key
+    file: Option<syn::LitStr>,
+}
+
+impl syn::parse::Parse for Args {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        while !input.is_empty() {
+            let key: syn::Ident = input.parse()?;
+            input.parse::<syn::Token![=]>()?;
+            match key.to_string().as_str() {
+                "consts" if args.consts.is_none() => args.consts = Some(input.parse()?),
+                "env_prefix" if args.env_prefix.is_none() => args.env_prefix = Some(input.parse()?),
+                "file" if args.file.is_none() => args.file = Some(input.parse()?),
+                "consts" | "env_prefix" | "file" => {
+                    return Err(syn::Error::new_spanned(&key, format!("duplicate `{key}`")))
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        key,
+                        "expected `consts = path`, `env_prefix = \"PREFIX_\"` or `file = \"defaults.toml\"`",
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Adds `#[serde(default = "Name::default_<field>")]` to fields with a `#[default(...)]` value,
+/// `#[default(...)]` first from the file or `consts::FIELD` when given, and `#[serde(default)]` to the rest.
+/// Fields that already name a serde default are left as they are
+pub(crate) fn expand(args: &Args, mut item: DeriveInput) -> syn::Result<TokenStream> {
+    let name = item.ident.clone();
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+    let turbofish = ty_generics.as_turbofish();
+    let fields = match &mut item.data {
+        Data::Struct(data) => match &mut data.fields {
+            Fields::Named(fields) => &mut fields.named,
+            _ => return Err(unsupported(&name)),
+        },
+        _ => return Err(unsupported(&name)),
+    };
+
+    #[cfg(feature = "file")]
+    let mut file = args.file.as_ref().map(crate::file::load).transpose()?;
+    #[cfg(not(feature = "file"))]
+    if let Some(file) = &args.file {
+        return Err(syn::Error::new_spanned(
+            file,
+            "`file` needs the `file` feature of serde_default_utils",
+        ));
+    }
+
+    let mut needs_derive = false;
+    for field in fields.iter_mut() {
+        let ident = field.ident.as_ref().expect("named field").unraw();
+        #[cfg(feature = "file")]
+        let from_file = file
+            .as_mut()
+            .and_then(|(_, table)| table.remove(&ident.to_string()));
+        if serde_metas(&field.attrs).any(|meta| meta.path().is_ident("default")) {
+            continue;
+        }
+        // `#[serde_default(env = "...")]` reads a variable before its fallback
+        let mut has_value = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("default") || reads_env(attr));
+        #[cfg(feature = "file")]
+        if let (false, Some(value)) = (has_value, &from_file) {
+            let value = crate::file::value_expr(value, &field.ty);
+            field.attrs.push(parse_quote!(#[default(#value)]));
+            has_value = true;
+        }
+        if let (false, Some(consts)) = (has_value, &args.consts) {
+            let value = format_ident!("{}", ident.to_string().to_uppercase(), span = ident.span());
+            field.attrs.push(parse_quote!(#[default(#consts::#value)]));
+            has_value = true;
+        }
+        if has_value {
+            needs_derive = true;
+            let default_fn = format_ident!("default_{}", ident);
+            let path = quote!(#name #turbofish::#default_fn).to_string();
+            field.attrs.push(parse_quote!(#[serde(default = #path)]));
+        } else {
+            field.attrs.push(parse_quote!(#[serde(default)]));
+        }
+    }
+
+    if let Some(env_prefix) = &args.env_prefix {
+        needs_derive = true;
+        item.attrs
+            .push(parse_quote!(#[serde_default(env_prefix = #env_prefix)]));
+    }
+
+    let derived = item.attrs.iter().any(|attr| {
+        attr.path().is_ident("derive")
+            && attr
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+                )
+                .is_ok_and(|paths| {
+                    paths.iter().any(|path| {
+                        path.segments
+                            .last()
+                            .is_some_and(|segment| segment.ident == "SerdeDefault")
+                    })
+                })
+    });
+    if needs_derive && !derived {
+        // ahead of the `#[serde_default(...)]` helpers it introduces
+        item.attrs.insert(
+            0,
+            parse_quote!(#[derive(::serde_default_utils::SerdeDefault)]),
+        );
+    }
+    #[cfg(feature = "file")]
+    if let Some((path, table)) = file {
+        if let Some(key) = table.keys().next() {
+            let lit = args.file.as_ref().expect("file was loaded");
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("`{key}` in the defaults file isn't a field of `{name}`"),
+            ));
+        }
+        // rebuilds the item when the file changes
+        return Ok(quote! {
+            #item
+            const _: &[u8] = ::core::include_bytes!(#path);
+        });
+    }
+    Ok(quote!(#item))
+}
+
+fn unsupported(name: &syn::Ident) -> syn::Error {
+    syn::Error::new_spanned(
+        name,
+        "serde_defaults supports only structs with named fields",
+    )
+}
+
+/// `#[serde_default(env = "...")]`, a variable read before its fallback
+fn reads_env(attr: &syn::Attribute) -> bool {
+    match &attr.meta {
+        syn::Meta::List(list) if list.path.is_ident("serde_default") => {
+            list.tokens.clone().into_iter().any(|token| {
+                matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "env" || ident == "fallback")
+            })
+        }
+        _ => false,
+    }
+}
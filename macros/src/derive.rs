@@ -0,0 +1,623 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::{Data, DeriveInput, Fields, Token};
+
+use crate::check::{serde_metas, serde_paths, SerdePath};
+
+/// Options given as `#[serde_default(...)]` on the struct
+#[derive(Default)]
+struct Container {
+    /// `const`, every field is built in a `const` context and `Name::DEFAULT` is generated
+    constant: bool,
+    /// `json`, `Name::defaults_json()` is generated
+    json: bool,
+    /// `is_default`, an `is_default_<field>(&value)` predicate is generated for every field
+    is_default: bool,
+    /// `partial` or `partial(derive(...))`, `<Name>Partial` with every field optional is generated
+    partial: Option<Vec<syn::Path>>,
+    /// `merge`, `Name::merge` overlaying another instance is generated, with `merge_partial` for `partial`
+    merge: bool,
+    /// `env_prefix = "APP_"`, `Name::from_env()` reading `APP_<FIELD>` variables is generated
+    env_prefix: Option<syn::LitStr>,
+}
+
+impl Container {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut container = Self::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("serde_default"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("const") {
+                    container.constant = true;
+                    Ok(())
+                } else if meta.path.is_ident("json") {
+                    container.json = true;
+                    Ok(())
+                } else if meta.path.is_ident("is_default") {
+                    container.is_default = true;
+                    Ok(())
+                } else if meta.path.is_ident("env_prefix") {
+                    container.env_prefix = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("merge") {
+                    container.merge = true;
+                    Ok(())
+                } else if meta.path.is_ident("partial") {
+                    let derives = container.partial.insert(Vec::new());
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|meta| {
+                            if !meta.path.is_ident("derive") {
+                                return Err(meta.error("expected `derive(...)`"));
+                            }
+                            let content;
+                            syn::parenthesized!(content in meta.input);
+                            derives.extend(Punctuated::<syn::Path, Token![,]>::parse_terminated(
+                                &content,
+                            )?);
+                            Ok(())
+                        })?;
+                    }
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown serde_default option"))
+                }
+            })?;
+        }
+        Ok(container)
+    }
+}
+
+/// Named field of a `SerdeDefault` struct with its `#[default(...)]` value,
+/// or the `fallback` of `#[serde_default(env = "...", fallback = ...)]`
+struct Field<'a> {
+    field: &'a syn::Field,
+    name: &'a syn::Ident,
+    default: Option<syn::Expr>,
+    /// Function of the field's own `#[serde(default = "...")]`, called by `Default` without a `#[default(...)]`
+    serde_default: Option<SerdePath>,
+    /// Environment variable read before falling back to `default`
+    env: Option<syn::LitStr>,
+    /// `nested`, `from_env()` reads the field with the type's own `from_env_prefixed`
+    nested: bool,
+    /// `skip_env`, `from_env()` leaves the field at its default
+    skip_env: bool,
+}
+
+impl Field<'_> {
+    /// `default_port` for `port`, the function serde's `default = "..."` points at
+    fn default_fn(&self) -> syn::Ident {
+        format_ident!("default_{}", self.name.unraw())
+    }
+
+    /// Whether the field gets a `default_<field>()` function
+    fn has_default_fn(&self) -> bool {
+        self.default.is_some() || self.env.is_some()
+    }
+
+    /// `Self::default_<field>()`, the field's serde default function or the type's `Default`
+    fn default_value(&self) -> TokenStream {
+        if self.has_default_fn() {
+            let default_fn = self.default_fn();
+            quote!(Self::#default_fn())
+        } else if let Some(path) = &self.serde_default {
+            quote!(#path())
+        } else {
+            let ty = &self.field.ty;
+            quote!(<#ty as ::core::default::Default>::default())
+        }
+    }
+}
+
+fn fields(item: &DeriveInput) -> syn::Result<Vec<Field<'_>>> {
+    let fields = match &item.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &item.ident,
+                    "SerdeDefault supports only structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &item.ident,
+                "SerdeDefault supports only structs with named fields",
+            ))
+        }
+    };
+    fields
+        .iter()
+        .map(|field| {
+            let mut default = None;
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("default"))
+            {
+                if default.is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicate #[default(...)]"));
+                }
+                default = Some(attr.parse_args()?);
+            }
+            let (mut env, mut nested, mut skip_env) = (None, false, false);
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("serde_default"))
+            {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("env") {
+                        env = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("fallback") {
+                        if default.is_some() {
+                            return Err(meta.error(
+                                "`fallback` and `#[default(...)]` are the same value, keep one",
+                            ));
+                        }
+                        default = Some(meta.value()?.parse()?);
+                        Ok(())
+                    } else if meta.path.is_ident("nested") {
+                        nested = true;
+                        Ok(())
+                    } else if meta.path.is_ident("skip_env") {
+                        skip_env = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error(
+                            "expected `env = \"VAR\"`, `fallback = value`, `nested` or `skip_env`",
+                        ))
+                    }
+                })?;
+            }
+            Ok(Field {
+                field,
+                name: field.ident.as_ref().expect("named field"),
+                default,
+                serde_default: serde_paths(&field.attrs, "default").pop(),
+                env,
+                nested,
+                skip_env,
+            })
+        })
+        .collect()
+}
+
+/// `impl Default` built from `#[default(...)]` values and a `default_<field>()` function for each of them,
+/// with `#[serde_default(const)]` these are `const fn` taking the value as is and `Name::DEFAULT` is added
+pub(crate) fn expand(item: &DeriveInput) -> syn::Result<TokenStream> {
+    let container = Container::parse(&item.attrs)?;
+    let fields = fields(item)?;
+    if container.constant {
+        if let Some(field) = fields.iter().find(|field| field.default.is_none()) {
+            return Err(syn::Error::new_spanned(
+                field.name,
+                "`#[serde_default(const)]` needs a `#[default(...)]` on every field",
+            ));
+        }
+        if let Some(env) = fields.iter().find_map(|field| field.env.as_ref()) {
+            return Err(syn::Error::new_spanned(
+                env,
+                "`#[serde_default(const)]` can't read the environment",
+            ));
+        }
+    }
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let bounded = default_bounded(&item.generics);
+    let (default_generics, _, default_where) = bounded.split_for_impl();
+
+    let default_fns = fields.iter().filter_map(|field| {
+        let (vis, ty) = (&field.field.vis, &field.field.ty);
+        let default_fn = field.default_fn();
+        if let Some(env) = &field.env {
+            let fallback = match &field.default {
+                Some(value) => {
+                    quote!(::serde_default_utils::__private::LitInto::<#ty>::lit_into(#value))
+                }
+                None => quote!(<#ty as ::core::default::Default>::default()),
+            };
+            let doc = match &field.default {
+                Some(value) => format!(
+                    "Default value of `{}`: `${}`, or `{}` when it isn't set",
+                    field.name,
+                    env.value(),
+                    quote!(#value)
+                ),
+                None => format!("Default value of `{}`: `${}`", field.name, env.value()),
+            };
+            let invalid = format!("invalid value of ${}", env.value());
+            return Some(quote! {
+                #[doc = #doc]
+                ///
+                /// # Panics
+                ///
+                /// If the variable is set but isn't unicode or doesn't parse
+                #vis fn #default_fn() -> #ty {
+                    match ::std::env::var(#env) {
+                        ::core::result::Result::Ok(value) => {
+                            <#ty as ::core::str::FromStr>::from_str(&value)
+                                .unwrap_or_else(|_| ::core::panic!("{}: {:?}", #invalid, value))
+                        }
+                        ::core::result::Result::Err(::std::env::VarError::NotPresent) => #fallback,
+                        ::core::result::Result::Err(error) => ::core::panic!("{}: {}", #invalid, error),
+                    }
+                }
+            });
+        }
+        let value = field.default.as_ref()?;
+        let doc = format!("Default value of `{}`: `{}`", field.name, quote!(#value));
+        Some(if container.constant {
+            quote! {
+                #[doc = #doc]
+                #vis const fn #default_fn() -> #ty {
+                    #value
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #vis fn #default_fn() -> #ty {
+                    ::serde_default_utils::__private::LitInto::<#ty>::lit_into(#value)
+                }
+            }
+        })
+    });
+    let values = fields.iter().map(|field| {
+        let (name, default) = (field.name, field.default_value());
+        quote!(#name: #default)
+    });
+
+    let is_default_fns = fields.iter().map(|field| {
+        let (vis, ty) = (&field.field.vis, &field.field.ty);
+        let is_default_fn = format_ident!("is_default_{}", field.name.unraw());
+        let doc = format!(
+            "Whether `{}` is at its default, for `skip_serializing_if`",
+            field.name
+        );
+        let default = field.default_value();
+        quote! {
+            #[doc = #doc]
+            #vis fn #is_default_fn(value: &#ty) -> bool {
+                *value == #default
+            }
+        }
+    });
+    let is_default_fns = container.is_default.then(|| {
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #(#is_default_fns)*
+            }
+        }
+    });
+
+    let partial = container
+        .partial
+        .as_ref()
+        .map(|derives| partial(item, &fields, derives));
+
+    let from_env = container
+        .env_prefix
+        .as_ref()
+        .map(|prefix| from_env(item, &fields, prefix));
+
+    let merge = container
+        .merge
+        .then(|| merge(item, &fields, container.partial.is_some()));
+
+    let defaults_json = container.json.then(|| {
+        let vis = &item.vis;
+        quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Every field at its default as a JSON object, keyed the way serde serializes them
+                ///
+                /// # Panics
+                ///
+                /// If a default can't be represented in JSON, like a map with non-string keys
+                #vis fn defaults_json() -> ::serde_default_utils::__private::serde_json::Value
+                where
+                    Self: ::core::default::Default + ::serde_default_utils::__private::serde::Serialize,
+                {
+                    ::serde_default_utils::__private::serde_json::to_value(
+                        <Self as ::core::default::Default>::default(),
+                    )
+                    .expect("defaults serialize to JSON")
+                }
+
+                /// Only the fields that differ from their defaults as a JSON object,
+                /// nested objects keep only their changed members
+                ///
+                /// # Panics
+                ///
+                /// If a value can't be represented in JSON, like a map with non-string keys
+                #vis fn diff_from_default(&self) -> ::serde_default_utils::__private::serde_json::Value
+                where
+                    Self: ::core::default::Default + ::serde_default_utils::__private::serde::Serialize,
+                {
+                    let value = ::serde_default_utils::__private::serde_json::to_value(self)
+                        .expect("values serialize to JSON");
+                    ::serde_default_utils::__private::json_diff(value, &Self::defaults_json())
+                }
+
+                /// Applies a JSON Merge Patch (RFC 7386), `null` resets a field to what serde fills in when it's missing
+                #vis fn apply_merge_patch(
+                    &mut self,
+                    patch: ::serde_default_utils::__private::serde_json::Value,
+                ) -> ::core::result::Result<(), ::serde_default_utils::__private::serde_json::Error>
+                where
+                    Self: ::serde_default_utils::__private::serde::Serialize
+                        + ::serde_default_utils::__private::serde::de::DeserializeOwned,
+                {
+                    let mut value = ::serde_default_utils::__private::serde_json::to_value(&*self)?;
+                    ::serde_default_utils::__private::json_merge_patch(&mut value, patch);
+                    *self = ::serde_default_utils::__private::serde_json::from_value(value)?;
+                    ::core::result::Result::Ok(())
+                }
+
+                /// JSON Merge Patch (RFC 7386) that turns the defaults into `self`
+                ///
+                /// # Panics
+                ///
+                /// If a value can't be represented in JSON, like a map with non-string keys
+                #vis fn merge_patch_from_default(&self) -> ::serde_default_utils::__private::serde_json::Value
+                where
+                    Self: ::core::default::Default + ::serde_default_utils::__private::serde::Serialize,
+                {
+                    let value = ::serde_default_utils::__private::serde_json::to_value(self)
+                        .expect("values serialize to JSON");
+                    ::serde_default_utils::__private::json_create_merge_patch(&Self::defaults_json(), value)
+                }
+
+                /// JSON Patch (RFC 6902) operations that turn the defaults into `self`,
+                /// one per changed field with nested objects compared member by member
+                ///
+                /// # Panics
+                ///
+                /// If a value can't be represented in JSON, like a map with non-string keys
+                #vis fn json_patch_from_default(&self) -> ::serde_default_utils::__private::serde_json::Value
+                where
+                    Self: ::core::default::Default + ::serde_default_utils::__private::serde::Serialize,
+                {
+                    let value = ::serde_default_utils::__private::serde_json::to_value(self)
+                        .expect("values serialize to JSON");
+                    ::serde_default_utils::__private::json_create_patch(&Self::defaults_json(), value)
+                }
+            }
+        }
+    });
+
+    if container.constant {
+        let vis = &item.vis;
+        let doc = format!(
+            "`{name}` with every field at its default, usable in `const` and `static` items"
+        );
+        return Ok(quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                #(#default_fns)*
+
+                #[doc = #doc]
+                #vis const DEFAULT: Self = Self {
+                    #(#values,)*
+                };
+            }
+
+            impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self::DEFAULT
+                }
+            }
+
+            #is_default_fns
+            #defaults_json
+            #partial
+            #merge
+            #from_env
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            #(#default_fns)*
+        }
+
+        impl #default_generics ::core::default::Default for #name #ty_generics #default_where {
+            fn default() -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+        }
+
+        #is_default_fns
+        #defaults_json
+        #partial
+        #merge
+        #from_env
+    })
+}
+
+/// `<Name>Partial` with every field wrapped in `Option`, and `Name::from_partial` filling the rest from the defaults.
+/// With serde derives the renames of the struct and its fields carry over, so both read the same input
+fn partial(item: &DeriveInput, fields: &[Field], derives: &[syn::Path]) -> TokenStream {
+    let (name, vis) = (&item.ident, &item.vis);
+    let partial = format_ident!("{}Partial", name);
+    let (_, ty_generics, where_clause) = item.generics.split_for_impl();
+    let bounded = default_bounded(&item.generics);
+    let (default_generics, _, default_where) = bounded.split_for_impl();
+    let generics = &item.generics;
+
+    let derives_trait = |trait_name: &str| {
+        derives.iter().any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == trait_name)
+        })
+    };
+    let serde = derives_trait("Serialize") || derives_trait("Deserialize");
+    let serde_attrs = |attrs: &[syn::Attribute], keys: &[&str]| {
+        let metas: Vec<syn::Meta> = serde_metas(attrs)
+            .filter(|meta| keys.iter().any(|key| meta.path().is_ident(key)))
+            .collect();
+        (serde && !metas.is_empty()).then(|| quote!(#[serde(#(#metas),*)]))
+    };
+    let container_attrs = serde_attrs(&item.attrs, &["rename_all", "deny_unknown_fields"]);
+    let skip_none = derives_trait("Serialize")
+        .then(|| quote!(#[serde(skip_serializing_if = "::core::option::Option::is_none")]));
+
+    let partial_fields = fields.iter().map(|field| {
+        let (vis, name, ty) = (&field.field.vis, field.name, &field.field.ty);
+        let renames = serde_attrs(&field.field.attrs, &["rename", "alias"]);
+        quote! {
+            #renames
+            #skip_none
+            #vis #name: ::core::option::Option<#ty>
+        }
+    });
+    let values = fields.iter().map(|field| {
+        let name = field.name;
+        if field.has_default_fn() {
+            let default_fn = field.default_fn();
+            quote!(#name: partial.#name.unwrap_or_else(Self::#default_fn))
+        } else {
+            quote!(#name: partial.#name.unwrap_or_default())
+        }
+    });
+    let doc = format!("[`{name}`] with every field optional, see [`{name}::from_partial`]");
+
+    quote! {
+        #[doc = #doc]
+        #[derive(::core::default::Default #(, #derives)*)]
+        #container_attrs
+        #vis struct #partial #generics #where_clause {
+            #(#partial_fields,)*
+        }
+
+        impl #default_generics #name #ty_generics #default_where {
+            /// Takes the fields that are set and the defaults for the rest
+            #vis fn from_partial(partial: #partial #ty_generics) -> Self {
+                Self {
+                    #(#values,)*
+                }
+            }
+        }
+
+        impl #default_generics ::core::convert::From<#partial #ty_generics> for #name #ty_generics #default_where {
+            fn from(partial: #partial #ty_generics) -> Self {
+                Self::from_partial(partial)
+            }
+        }
+    }
+}
+
+/// `Name::merge` taking every field of `other` that isn't at its default,
+/// and `Name::merge_partial` taking every field that is set when there is a partial
+fn merge(item: &DeriveInput, fields: &[Field], partial: bool) -> TokenStream {
+    let (name, vis) = (&item.ident, &item.vis);
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+    let bounded = default_bounded(&item.generics);
+    let (default_generics, _, default_where) = bounded.split_for_impl();
+    let tys = fields.iter().map(|field| &field.field.ty);
+
+    let overlays = fields.iter().map(|field| {
+        let (name, default) = (field.name, field.default_value());
+        quote! {
+            if other.#name != #default {
+                self.#name = other.#name;
+            }
+        }
+    });
+    let merge_partial = partial.then(|| {
+        let partial = format_ident!("{}Partial", name);
+        let names = fields.iter().map(|field| field.name);
+        let doc = format!("Takes every field of `partial` that is set, see [`{partial}`]");
+        quote! {
+            #[doc = #doc]
+            #vis fn merge_partial(&mut self, partial: #partial #ty_generics) {
+                #(
+                    if let ::core::option::Option::Some(value) = partial.#names {
+                        self.#names = value;
+                    }
+                )*
+            }
+        }
+    });
+
+    quote! {
+        impl #default_generics #name #ty_generics #default_where {
+            /// Takes every field of `other` that isn't at its default, a default value counts as unset
+            #vis fn merge(&mut self, other: Self)
+            where
+                #(#tys: ::core::cmp::PartialEq,)*
+            {
+                #(#overlays)*
+            }
+
+            #merge_partial
+        }
+    }
+}
+
+/// `Name::from_env()` reading `<prefix><FIELD>` variables and `from_env_prefixed` for other prefixes,
+/// `nested` fields read `<prefix><FIELD>__<NESTED>` through their own `from_env_prefixed`
+fn from_env(item: &DeriveInput, fields: &[Field], prefix: &syn::LitStr) -> TokenStream {
+    let (name, vis) = (&item.ident, &item.vis);
+    let (_, ty_generics, _) = item.generics.split_for_impl();
+    let bounded = default_bounded(&item.generics);
+    let (default_generics, _, default_where) = bounded.split_for_impl();
+
+    let values = fields.iter().map(|field| {
+        let (name, ty, default) = (field.name, &field.field.ty, field.default_value());
+        let var = name.unraw().to_string().to_uppercase();
+        if field.skip_env {
+            quote!(#name: #default)
+        } else if field.nested {
+            let nested = format!("{var}__");
+            quote!(#name: <#ty>::from_env_prefixed(&::std::format!("{}{}", prefix, #nested))?)
+        } else {
+            quote! {
+                #name: match ::serde_default_utils::__private::env_var::<#ty>(&::std::format!("{}{}", prefix, #var))? {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => #default,
+                }
+            }
+        }
+    });
+    let doc = format!(
+        "Builds `{name}` from `{}<FIELD>` variables, the defaults fill in the ones that aren't set",
+        prefix.value()
+    );
+
+    quote! {
+        impl #default_generics #name #ty_generics #default_where {
+            #[doc = #doc]
+            #vis fn from_env() -> ::core::result::Result<Self, ::serde_default_utils::FromEnvError> {
+                Self::from_env_prefixed(#prefix)
+            }
+
+            /// Like `from_env()` with another prefix, for `#[serde_default(nested)]` fields of other structs
+            #vis fn from_env_prefixed(
+                prefix: &str,
+            ) -> ::core::result::Result<Self, ::serde_default_utils::FromEnvError> {
+                ::core::result::Result::Ok(Self {
+                    #(#values,)*
+                })
+            }
+        }
+    }
+}
+
+/// Generics with `Default` on every type parameter, like `#[derive(Default)]` adds
+fn default_bounded(generics: &syn::Generics) -> syn::Generics {
+    let mut bounded = generics.clone();
+    for param in bounded.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote!(::core::default::Default));
+    }
+    bounded
+}
@@ -0,0 +1,144 @@
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+
+/// Keywords whose literal says where the value comes from rather than what it is
+const SOURCES: &[&str] = &["cfg", "env", "cache_dir", "config_dir", "data_dir"];
+
+/// The value of a `serde_default!` tail as text, `8080` for `u16 = 8080` and `"5s"` for `duration "5s"`.
+/// A tail without a separate value, like flags or `&[1, 2]`, or one read from a source like `env` stays whole
+pub(crate) fn value(tail: TokenStream) -> String {
+    let tokens: Vec<TokenTree> = tail.into_iter().collect();
+    let value = after_eq(&tokens)
+        .or_else(|| after_keyword(&tokens))
+        .unwrap_or(&tokens);
+    let mut text = String::new();
+    print(value, &mut text);
+    text
+}
+
+/// What follows the `=` of `Type = value`
+fn after_eq(tokens: &[TokenTree]) -> Option<&[TokenTree]> {
+    let mut depth = 0usize;
+    let mut previous_joint = false;
+    for (i, token) in tokens.iter().enumerate() {
+        let TokenTree::Punct(punct) = token else {
+            previous_joint = false;
+            continue;
+        };
+        match punct.as_char() {
+            '<' => depth += 1,
+            // `->` isn't closing anything
+            '>' if !previous_joint => depth = depth.saturating_sub(1),
+            '=' if depth == 0 && !previous_joint && punct.spacing() == Spacing::Alone => {
+                return Some(&tokens[i + 1..]);
+            }
+            _ => {}
+        }
+        previous_joint = punct.spacing() == Spacing::Joint;
+    }
+    None
+}
+
+/// What follows a keyword like `duration` or `parse u64` when it's a literal, `-literal` or a group
+fn after_keyword(tokens: &[TokenTree]) -> Option<&[TokenTree]> {
+    let keyword = tokens.iter().find_map(|token| match token {
+        TokenTree::Ident(ident) => Some(ident.to_string()),
+        _ => None,
+    })?;
+    if SOURCES.contains(&keyword.as_str()) {
+        return None;
+    }
+    let (mut depth, mut idents) = (0usize, 0usize);
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            // `<8>` of `arrayvec<8>`
+            TokenTree::Punct(punct) if punct.as_char() == '<' => depth += 1,
+            TokenTree::Punct(punct) if punct.as_char() == '>' && depth > 0 => depth -= 1,
+            _ if depth > 0 => {}
+            TokenTree::Ident(_) => idents += 1,
+            TokenTree::Punct(punct) if matches!(punct.as_char(), '&' | ':') => {}
+            TokenTree::Punct(punct) if punct.as_char() != '-' => return None,
+            TokenTree::Punct(_) | TokenTree::Literal(_) | TokenTree::Group(_) => {
+                return (idents > 0).then(|| &tokens[i..]);
+            }
+        }
+    }
+    None
+}
+
+/// Prints tokens the way they're usually written, `Duration::from_secs(5)` rather than the
+/// `Duration :: from_secs(5)` that `stringify!` makes of tokens which went through a proc macro
+fn print(tokens: &[TokenTree], text: &mut String) {
+    let mut turbofish = 0usize;
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 && !glued(tokens, i, turbofish) {
+            text.push(' ');
+        }
+        match token {
+            TokenTree::Group(group) => {
+                let inner: Vec<TokenTree> = group.stream().into_iter().collect();
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace if inner.is_empty() => ("{", "}"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+                text.push_str(open);
+                print(&inner, text);
+                text.push_str(close);
+            }
+            TokenTree::Punct(punct) => {
+                match punct.as_char() {
+                    '<' if turbofish > 0 || path_sep(tokens, i.wrapping_sub(1)) => turbofish += 1,
+                    '>' if turbofish > 0 => turbofish -= 1,
+                    _ => {}
+                }
+                text.push(punct.as_char());
+            }
+            token => text.push_str(&token.to_string()),
+        }
+    }
+}
+
+/// Whether the token at `i` follows the one before it without a space
+fn glued(tokens: &[TokenTree], i: usize, turbofish: usize) -> bool {
+    let is = |token: &TokenTree, chars: &[char]| matches!(token, TokenTree::Punct(punct) if chars.contains(&punct.as_char()));
+    let (previous, token) = (&tokens[i - 1], &tokens[i]);
+    match previous {
+        TokenTree::Punct(punct) if punct.spacing() == Spacing::Joint => return true,
+        // `a.b` and `a::b`
+        _ if is(previous, &['.']) || path_sep(tokens, i - 1) => return true,
+        // `<T>` of `a::<T>`
+        _ if turbofish > 0 && (is(previous, &['<']) || is(token, &['>'])) => return true,
+        // `vec![1]`
+        _ if is(previous, &['!']) && i > 1 && matches!(&tokens[i - 2], TokenTree::Ident(_)) => {
+            return true;
+        }
+        // prefix `&x`, `-1`, `!x` and `*x`
+        _ if is(previous, &['&', '-', '!', '*']) => {
+            return i == 1 || matches!(&tokens[i - 2], TokenTree::Punct(_));
+        }
+        _ => {}
+    }
+    match token {
+        TokenTree::Punct(punct) => {
+            matches!(punct.as_char(), ',' | ';' | '.' | '?')
+                || punct.as_char() == ':' && punct.spacing() == Spacing::Joint
+                // `vec!`
+                || punct.as_char() == '!' && matches!(previous, TokenTree::Ident(_))
+        }
+        // `f(x)` and `a[0]`
+        TokenTree::Group(group) => {
+            group.delimiter() != Delimiter::Brace && matches!(previous, TokenTree::Ident(_))
+        }
+        _ => false,
+    }
+}
+
+/// Whether the `:` at `i` ends a `::`
+fn path_sep(tokens: &[TokenTree], i: usize) -> bool {
+    let colon =
+        |i: usize| matches!(tokens.get(i), Some(TokenTree::Punct(punct)) if punct.as_char() == ':');
+    let joint = |i: usize| matches!(&tokens[i], TokenTree::Punct(punct) if punct.spacing() == Spacing::Joint);
+    i > 0 && colon(i) && colon(i - 1) && joint(i - 1)
+}
@@ -0,0 +1,11 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+pub(crate) fn expand(lit: &LitStr) -> syn::Result<TokenStream> {
+    let duration = humantime::parse_duration(&lit.value())
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid duration: {e}")))?;
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    Ok(quote!(::core::time::Duration::new(#secs, #nanos)))
+}
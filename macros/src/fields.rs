@@ -0,0 +1,132 @@
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use quote::quote;
+
+/// `$crate; [vis] name: ..., name: ...` as passed to `mod_fields!`
+pub(crate) struct Fields {
+    krate: TokenTree,
+    vis: TokenStream,
+    fields: Vec<(syn::Ident, TokenStream)>,
+}
+
+impl syn::parse::Parse for Fields {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let krate = input.parse()?;
+        input.parse::<syn::Token![;]>()?;
+        let vis;
+        syn::bracketed!(vis in input);
+        let vis = vis.parse()?;
+        let mut fields = Vec::<(syn::Ident, TokenStream)>::new();
+        while !input.is_empty() {
+            let name: syn::Ident = input.parse()?;
+            let lower = name.to_string().to_lowercase();
+            if let Some((first, _)) = fields
+                .iter()
+                .find(|(first, _)| first.to_string().to_lowercase() == lower)
+            {
+                let mut error = syn::Error::new_spanned(
+                    &name,
+                    format!("`default_{lower}` is already generated for `{first}`"),
+                );
+                error.combine(syn::Error::new_spanned(
+                    first,
+                    format!("`default_{lower}` is first generated here"),
+                ));
+                return Err(error);
+            }
+            input.parse::<syn::Token![:]>()?;
+            let mut value = value_tokens(input)?;
+            if value.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    "expected a default after `:`",
+                ));
+            }
+            // `name: Type, cfg { ... }` is one field, passed on as `name, cfg Type { ... }`
+            if is_typed_cfg(input, &value) {
+                input.parse::<syn::Token![,]>()?;
+                let cfg: syn::Ident = input.parse()?;
+                let arms: TokenTree = input.parse()?;
+                value = quote!(#cfg #value #arms);
+            }
+            fields.push((name, value));
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(Self { krate, vis, fields })
+    }
+}
+
+/// Takes tokens up to the next `,` outside of generic arguments, so `Either<u32, String> = Left(1)` stays whole.
+///
+/// Every `<` of the type opens generic arguments, in the value after its `=` only a turbofish `::<`
+/// or a leading `<T as Trait>` does, so `1 << 4` and `a < b` end at the next `,`
+fn value_tokens(input: syn::parse::ParseStream) -> syn::Result<TokenStream> {
+    input.step(|cursor| {
+        let mut rest = *cursor;
+        let mut tokens = TokenStream::new();
+        let mut depth = 0usize;
+        let mut in_value = false;
+        // `None` before the first token of the value, `Some(true)` right after `::`
+        let mut after_path_sep = None;
+        let mut previous_joint = None;
+        while let Some((tree, next)) = rest.token_tree() {
+            let mut path_sep = false;
+            if let TokenTree::Punct(punct) = &tree {
+                let ch = punct.as_char();
+                match ch {
+                    ',' if depth == 0 => break,
+                    '<' if depth > 0 || !in_value || after_path_sep != Some(false) => depth += 1,
+                    // `->` and `=>` aren't closing anything
+                    '>' if !matches!(previous_joint, Some('-' | '=')) => {
+                        depth = depth.saturating_sub(1)
+                    }
+                    // a lone `=`, not `==`, `<=`, `>=`, `!=` or `=>`
+                    '=' if depth == 0
+                        && !in_value
+                        && previous_joint.is_none()
+                        && punct.spacing() == Spacing::Alone =>
+                    {
+                        in_value = true;
+                        tokens.extend([tree]);
+                        rest = next;
+                        after_path_sep = None;
+                        previous_joint = None;
+                        continue;
+                    }
+                    ':' => path_sep = previous_joint == Some(':'),
+                    _ => {}
+                }
+                previous_joint = (punct.spacing() == Spacing::Joint).then_some(ch);
+            } else {
+                previous_joint = None;
+            }
+            if previous_joint != Some(':') {
+                after_path_sep = Some(path_sep);
+            }
+            tokens.extend([tree]);
+            rest = next;
+        }
+        Ok((tokens, rest))
+    })
+}
+
+/// Whether a `Type` value is followed by `, cfg { ... }`
+fn is_typed_cfg(input: syn::parse::ParseStream, value: &TokenStream) -> bool {
+    let fork = input.fork();
+    fork.parse::<syn::Token![,]>().is_ok()
+        && fork.parse::<syn::Ident>().is_ok_and(|ident| ident == "cfg")
+        && fork.peek(syn::token::Brace)
+        && syn::parse2::<syn::Type>(value.clone()).is_ok()
+}
+
+/// One `serde_default!` call per field, all with the same visibility
+pub(crate) fn expand(fields: &Fields) -> TokenStream {
+    let krate = &fields.krate;
+    let vis = &fields.vis;
+    fields
+        .fields
+        .iter()
+        .map(|(name, value)| quote!(#krate::serde_default!(#vis #name, #value);))
+        .collect()
+}
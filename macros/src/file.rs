@@ -0,0 +1,76 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{quote, ToTokens};
+use serde_json::{Map, Value};
+
+/// Top-level table of a `.toml` or `.json` file relative to the crate being built, with its full path
+pub(crate) fn load(lit: &syn::LitStr) -> syn::Result<(String, Map<String, Value>)> {
+    let error = |message: String| syn::Error::new(lit.span(), message);
+    let dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|e| error(e.to_string()))?;
+    let path = std::path::Path::new(&dir).join(lit.value());
+    let text = std::fs::read_to_string(&path)
+        .map_err(|e| error(format!("can't read {}: {e}", path.display())))?;
+    let value = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => {
+            let table: toml::Table =
+                toml::from_str(&text).map_err(|e| error(format!("invalid TOML: {e}")))?;
+            for (key, value) in &table {
+                reject_datetime(key, value).map_err(|key| {
+                    error(format!(
+                        "`{key}` is a TOML datetime, quote it as a string to use it as a default"
+                    ))
+                })?;
+            }
+            serde_json::to_value(table).map_err(|e| error(format!("invalid TOML: {e}")))?
+        }
+        Some("json") => {
+            serde_json::from_str(&text).map_err(|e| error(format!("invalid JSON: {e}")))?
+        }
+        _ => return Err(error("expected a `.toml` or `.json` file".into())),
+    };
+    match value {
+        Value::Object(table) => Ok((path.display().to_string(), table)),
+        _ => Err(error("expected a table of field defaults".into())),
+    }
+}
+
+/// Path of the first datetime within `value`, like `tls.expires` or `windows[1]`
+fn reject_datetime(key: &str, value: &toml::Value) -> Result<(), String> {
+    match value {
+        toml::Value::Datetime(_) => Err(key.into()),
+        toml::Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, value)| reject_datetime(&format!("{key}[{i}]"), value)),
+        toml::Value::Table(table) => table
+            .iter()
+            .try_for_each(|(nested, value)| reject_datetime(&format!("{key}.{nested}"), value)),
+        _ => Ok(()),
+    }
+}
+
+/// Strings, numbers and booleans become literals checked against the field type like `#[default(...)]`,
+/// anything else is deserialized from JSON into `ty` when the default is built
+pub(crate) fn value_expr(value: &Value, ty: &syn::Type) -> TokenStream {
+    match value {
+        Value::String(text) => quote!(#text),
+        Value::Bool(value) => quote!(#value),
+        Value::Number(number) => {
+            if let Some(number) = number.as_u64() {
+                Literal::u64_unsuffixed(number).into_token_stream()
+            } else if let Some(number) = number.as_i64() {
+                let number = Literal::u64_unsuffixed(number.unsigned_abs());
+                quote!(-#number)
+            } else {
+                let number = number.as_f64().unwrap_or_default();
+                Literal::f64_unsuffixed(number).into_token_stream()
+            }
+        }
+        _ => {
+            let json = value.to_string();
+            quote! {
+                ::serde_default_utils::__private::serde_json::from_str::<#ty>(#json)
+                    .expect("defaults file matches the field type")
+            }
+        }
+    }
+}
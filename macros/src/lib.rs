@@ -0,0 +1,368 @@
+//! Proc macros backing [serde_default_utils](https://docs.rs/serde_default_utils).
+//!
+//! These parse and validate literals passed to `serde_default!` at compile time,
+//! they are re-exported through a hidden module and not meant to be used directly.
+//! Macros that need to name types from optional dependencies take `$crate` as the first argument.
+
+mod check;
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+mod datetime;
+mod defaults;
+mod derive;
+mod doc;
+#[cfg(feature = "humantime")]
+mod duration;
+mod fields;
+#[cfg(feature = "file")]
+mod file;
+mod literal;
+
+/// Parses `$crate, "literal"` input
+#[allow(dead_code)]
+fn parse_krate_and_lit(
+    input: proc_macro::TokenStream,
+) -> syn::Result<(proc_macro2::TokenStream, syn::LitStr)> {
+    syn::parse::Parser::parse(
+        |input: syn::parse::ParseStream| {
+            let krate: proc_macro2::TokenTree = input.parse()?;
+            input.parse::<syn::Token![,]>()?;
+            let lit: syn::LitStr = input.parse()?;
+            Ok((krate.into(), lit))
+        },
+        input,
+    )
+}
+
+/// Expands `$crate, "literal"` input with the given function, turning errors into `compile_error!`
+#[allow(dead_code)]
+fn expand_with_krate(
+    input: proc_macro::TokenStream,
+    expand: fn(&proc_macro2::TokenStream, &syn::LitStr) -> syn::Result<proc_macro2::TokenStream>,
+) -> proc_macro::TokenStream {
+    parse_krate_and_lit(input)
+        .and_then(|(krate, lit)| expand(&krate, &lit))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Validates `lit` with `T: FromStr` here and expands to the same `FromStr` call at runtime,
+/// for types that can't be built in a `const` context
+#[allow(dead_code)]
+fn validated_from_str<T>(
+    lit: &syn::LitStr,
+    ty: proc_macro2::TokenStream,
+    what: &str,
+) -> syn::Result<proc_macro2::TokenStream>
+where
+    T: core::str::FromStr,
+    T::Err: core::fmt::Display,
+{
+    lit.value()
+        .parse::<T>()
+        .map_err(|e| syn::Error::new(lit.span(), format!("invalid {what}: {e}")))?;
+    let expect = format!("{what} validated at compile time");
+    Ok(quote::quote!(<#ty as ::core::str::FromStr>::from_str(#lit).expect(#expect)))
+}
+
+/// Expands to the element type of a list of literals, e.g. `u16` for `i32; 8080u16, 8081`,
+/// the leading type is used when every integer is unsuffixed
+#[proc_macro]
+pub fn lit_ty(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let list = syn::parse_macro_input!(input as literal::LitList);
+    literal::element_type(&list).into()
+}
+
+/// Adds the serde default of every field of a struct, see `serde_default_utils::serde_defaults`
+#[proc_macro_attribute]
+pub fn serde_defaults(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = syn::parse_macro_input!(args as defaults::Args);
+    let item = syn::parse_macro_input!(input as syn::DeriveInput);
+    defaults::expand(&args, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `Default` from `#[default(...)]` field values, with `default_<field>()` functions for serde
+#[proc_macro_derive(SerdeDefault, attributes(default, serde_default))]
+pub fn serde_default_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let item = syn::parse_macro_input!(input as syn::DeriveInput);
+    derive::expand(&item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Checks that functions named in `#[serde(default = "...")]` and `skip_serializing_if`
+/// exist and match the field type, the item itself is left as is
+#[proc_macro_attribute]
+pub fn check_defaults(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    if !args.is_empty() {
+        let args = proc_macro2::TokenStream::from(args);
+        return syn::Error::new_spanned(args, "check_defaults takes no arguments")
+            .into_compile_error()
+            .into();
+    }
+    let item = syn::parse_macro_input!(input as syn::DeriveInput);
+    check::expand(&item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Expands to the value of a `serde_default!` tail as a string literal, `"8080"` for `u16 = 8080`,
+/// for the `Default value: ...` doc of the generated function
+#[proc_macro]
+pub fn doc_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let value = doc::value(input.into());
+    quote::quote!(#value).into()
+}
+
+/// Expands `$crate; [pub(crate)] port: u16 = 8080, host: "localhost"` into one `serde_default!` call per field
+#[proc_macro]
+pub fn mod_fields(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let fields = syn::parse_macro_input!(input as fields::Fields);
+    fields::expand(&fields).into()
+}
+
+/// Parses a humantime duration like `"5m30s"` into a `const` `Duration` expression
+#[cfg(feature = "humantime")]
+#[proc_macro]
+pub fn duration(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let lit = syn::parse_macro_input!(input as syn::LitStr);
+    duration::expand(&lit)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Parses `"YYYY-MM-DD"` into a `const` `chrono::NaiveDate` expression
+#[cfg(feature = "chrono")]
+#[proc_macro]
+pub fn chrono_date(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, datetime::chrono_date)
+}
+
+/// Parses an RFC 3339 datetime into a `const` `chrono::DateTime<Utc>` expression
+#[cfg(feature = "chrono")]
+#[proc_macro]
+pub fn chrono_datetime(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, datetime::chrono_datetime)
+}
+
+/// Parses `"YYYY-MM-DD"` into a `const` `time::Date` expression
+#[cfg(feature = "time")]
+#[proc_macro]
+pub fn time_date(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, datetime::time_date)
+}
+
+/// Parses `"HH:MM:SS[.fraction]"` into a `const` `time::Time` expression
+#[cfg(feature = "time")]
+#[proc_macro]
+pub fn time_time(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, datetime::time_time)
+}
+
+/// Parses an RFC 3339 datetime into a `const` `time::OffsetDateTime` expression, keeping the offset
+#[cfg(feature = "time")]
+#[proc_macro]
+pub fn time_datetime(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, datetime::time_datetime)
+}
+
+/// Parses a timestamp like `"2024-01-01T00:00:00Z"` into a `const` `jiff::Timestamp` expression
+#[cfg(feature = "jiff")]
+#[proc_macro]
+pub fn jiff_timestamp(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, datetime::jiff_timestamp)
+}
+
+/// Validates an RFC 9557 datetime and expands to a runtime `jiff::Zoned` parse
+#[cfg(feature = "jiff")]
+#[proc_macro]
+pub fn jiff_zoned(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        // without `std` the runtime `jiff` has no time zone database to find the name in
+        #[cfg(not(feature = "std"))]
+        if let Ok(zoned) = lit.value().parse::<jiff::Zoned>() {
+            if let Some(name) = zoned.time_zone().iana_name() {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!(
+                        "time zone `{name}` needs the `std` feature, use an offset like `[+01:00]`"
+                    ),
+                ));
+            }
+        }
+        validated_from_str::<jiff::Zoned>(
+            lit,
+            quote::quote!(#krate::__private::jiff::Zoned),
+            "zoned datetime",
+        )
+    })
+}
+
+/// Validates a span like `"1h 30m"` or `"PT1H30M"` and expands to a runtime `jiff::Span` parse
+#[cfg(feature = "jiff")]
+#[proc_macro]
+pub fn jiff_span(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<jiff::Span>(lit, quote::quote!(#krate::__private::jiff::Span), "span")
+    })
+}
+
+/// Validates an IANA time zone name and expands to a runtime `chrono_tz::Tz` parse
+#[cfg(feature = "chrono-tz")]
+#[proc_macro]
+pub fn timezone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<chrono_tz::Tz>(
+            lit,
+            quote::quote!(#krate::__private::chrono_tz::Tz),
+            "time zone",
+        )
+    })
+}
+
+/// Validates a url and expands to a runtime `url::Url` parse
+#[cfg(feature = "url")]
+#[proc_macro]
+pub fn url(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<url::Url>(lit, quote::quote!(#krate::__private::url::Url), "url")
+    })
+}
+
+/// Validates an email address and expands to a runtime `email_address::EmailAddress` parse
+#[cfg(feature = "email")]
+#[proc_macro]
+pub fn email(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<email_address::EmailAddress>(
+            lit,
+            quote::quote!(#krate::__private::email_address::EmailAddress),
+            "email address",
+        )
+    })
+}
+
+/// Validates a decimal number and expands to a runtime `bigdecimal::BigDecimal` parse
+#[cfg(feature = "bigdecimal")]
+#[proc_macro]
+pub fn bigdecimal(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<bigdecimal::BigDecimal>(
+            lit,
+            quote::quote!(#krate::__private::bigdecimal::BigDecimal),
+            "decimal",
+        )
+    })
+}
+
+/// Validates an integer of any size and expands to a runtime `num_bigint::BigInt` parse
+#[cfg(feature = "num-bigint")]
+#[proc_macro]
+pub fn bigint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<num_bigint::BigInt>(
+            lit,
+            quote::quote!(#krate::__private::num_bigint::BigInt),
+            "integer",
+        )
+    })
+}
+
+/// Validates an unsigned integer of any size and expands to a runtime `num_bigint::BigUint` parse
+#[cfg(feature = "num-bigint")]
+#[proc_macro]
+pub fn biguint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<num_bigint::BigUint>(
+            lit,
+            quote::quote!(#krate::__private::num_bigint::BigUint),
+            "unsigned integer",
+        )
+    })
+}
+
+/// Validates a YAML document and expands to a runtime `serde_yaml::Value` parse
+#[cfg(feature = "yaml")]
+#[proc_macro]
+pub fn yaml(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        serde_yaml::from_str::<serde_yaml::Value>(&lit.value())
+            .map_err(|e| syn::Error::new(lit.span(), format!("invalid yaml: {e}")))?;
+        Ok(quote::quote! {
+            #krate::__private::serde_yaml::from_str::<#krate::__private::serde_yaml::Value>(#lit)
+                .expect("yaml validated at compile time")
+        })
+    })
+}
+
+/// Validates a regular expression and expands to a runtime `regex::Regex` compilation
+#[cfg(feature = "regex")]
+#[proc_macro]
+pub fn regex(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<regex::Regex>(
+            lit,
+            quote::quote!(#krate::__private::regex::Regex),
+            "regex",
+        )
+    })
+}
+
+/// Validates a cron expression and expands to a runtime `cron::Schedule` parse
+#[cfg(feature = "cron")]
+#[proc_macro]
+pub fn cron(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<cron::Schedule>(
+            lit,
+            quote::quote!(#krate::__private::cron::Schedule),
+            "cron expression",
+        )
+    })
+}
+
+/// Validates an HTTP method and expands to a runtime `http::Method` parse
+#[cfg(feature = "http")]
+#[proc_macro]
+pub fn http_method(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<http::Method>(
+            lit,
+            quote::quote!(#krate::__private::http::Method),
+            "http method",
+        )
+    })
+}
+
+/// Validates a media type and expands to a runtime `mime::Mime` parse
+#[cfg(feature = "mime")]
+#[proc_macro]
+pub fn mime(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<mime::Mime>(
+            lit,
+            quote::quote!(#krate::__private::mime::Mime),
+            "mime type",
+        )
+    })
+}
+
+/// Validates a BCP 47 language identifier and expands to a runtime `unic_langid::LanguageIdentifier` parse
+#[cfg(feature = "langid")]
+#[proc_macro]
+pub fn langid(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    expand_with_krate(input, |krate, lit| {
+        validated_from_str::<unic_langid::LanguageIdentifier>(
+            lit,
+            quote::quote!(#krate::__private::unic_langid::LanguageIdentifier),
+            "language identifier",
+        )
+    })
+}
@@ -0,0 +1,83 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{Expr, Lit, Token, UnOp};
+
+/// `int_fallback; items` as passed to `lit_ty!`
+pub(crate) struct LitList {
+    int_fallback: syn::Type,
+    items: Punctuated<Expr, Token![,]>,
+}
+
+impl syn::parse::Parse for LitList {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let int_fallback = input.parse()?;
+        input.parse::<Token![;]>()?;
+        let items = Punctuated::parse_terminated(input)?;
+        Ok(Self {
+            int_fallback,
+            items,
+        })
+    }
+}
+
+/// Picks the element type of a literal list the way rustc would,
+/// the first suffixed literal wins and unsuffixed ones fall back to `int_fallback`/`f64`,
+/// a list without any literal (e.g. only consts) falls back to `int_fallback` too
+pub(crate) fn element_type(list: &LitList) -> TokenStream {
+    let int_fallback = &list.int_fallback;
+    let mut fallback = None;
+    for item in &list.items {
+        match literal_type(item) {
+            Some(Inferred::Exact(ty)) => return ty,
+            Some(Inferred::Fallback(ty)) => {
+                fallback.get_or_insert(ty);
+            }
+            Some(Inferred::IntFallback) => {
+                fallback.get_or_insert(quote!(#int_fallback));
+            }
+            None => {}
+        }
+    }
+    fallback.unwrap_or_else(|| quote!(#int_fallback))
+}
+
+enum Inferred {
+    Exact(TokenStream),
+    Fallback(TokenStream),
+    IntFallback,
+}
+
+fn literal_type(expr: &Expr) -> Option<Inferred> {
+    match expr {
+        Expr::Group(group) => literal_type(&group.expr),
+        Expr::Paren(paren) => literal_type(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => match &*unary.expr {
+            Expr::Lit(lit) if matches!(lit.lit, Lit::Int(_) | Lit::Float(_)) => {
+                literal_type(&unary.expr)
+            }
+            _ => None,
+        },
+        Expr::Lit(lit) => Some(match &lit.lit {
+            Lit::Int(int) if int.suffix().is_empty() => Inferred::IntFallback,
+            Lit::Int(int) => Inferred::Exact(suffix(int.suffix(), int.span())),
+            Lit::Float(float) if float.suffix().is_empty() => {
+                Inferred::Fallback(quote!(::core::primitive::f64))
+            }
+            Lit::Float(float) => Inferred::Exact(suffix(float.suffix(), float.span())),
+            Lit::Str(_) => Inferred::Exact(quote!(&'static ::core::primitive::str)),
+            Lit::ByteStr(_) => Inferred::Exact(quote!(&'static [::core::primitive::u8])),
+            Lit::CStr(_) => Inferred::Exact(quote!(&'static ::core::ffi::CStr)),
+            Lit::Byte(_) => Inferred::Exact(quote!(::core::primitive::u8)),
+            Lit::Char(_) => Inferred::Exact(quote!(::core::primitive::char)),
+            Lit::Bool(_) => Inferred::Exact(quote!(::core::primitive::bool)),
+            _ => return None,
+        }),
+        _ => None,
+    }
+}
+
+fn suffix(suffix: &str, span: proc_macro2::Span) -> TokenStream {
+    let ty = syn::Ident::new(suffix, span);
+    quote!(::core::primitive::#ty)
+}
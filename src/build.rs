@@ -0,0 +1,87 @@
+//! Build script side of [`serde_default_build`](crate::serde_default_build)
+//!
+//! ```rust,no_run
+//! // in `main` of build.rs, with serde_default_utils under `[build-dependencies]`
+//! serde_default_utils::build::emit();
+//! ```
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Name of the variable holding the git commit hash
+pub const GIT_SHA_VAR: &str = "SERDE_DEFAULT_UTILS_GIT_SHA";
+/// Name of the variable holding the build timestamp
+pub const TIMESTAMP_VAR: &str = "SERDE_DEFAULT_UTILS_BUILD_TIMESTAMP";
+
+/// Passes the current git commit and an RFC 3339 build timestamp to the crate being built,
+/// `SOURCE_DATE_EPOCH` is used instead of the clock when set, so reproducible builds stay reproducible.
+///
+/// The build script reruns when `HEAD` moves or `SOURCE_DATE_EPOCH` changes. Like any
+/// `rerun-if` directive this replaces cargo's default of rerunning on every change in the package,
+/// so a build script that relies on that should print its own `cargo:rerun-if-changed` lines
+pub fn emit() {
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    for path in git_watched() {
+        println!("cargo:rerun-if-changed={path}");
+    }
+    if let Some(sha) = git(&["rev-parse", "HEAD"]) {
+        println!("cargo:rustc-env={GIT_SHA_VAR}={sha}");
+    }
+    let secs = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_secs())
+        });
+    println!("cargo:rustc-env={TIMESTAMP_VAR}={}", rfc3339(secs));
+}
+
+/// `.git/HEAD` and the branch it points to, or `packed-refs` when the branch isn't a loose file
+pub(crate) fn git_watched() -> Vec<String> {
+    let Some(head) = git(&["rev-parse", "--git-path", "HEAD"]) else {
+        return Vec::new();
+    };
+    let branch = std::fs::read_to_string(&head).ok().and_then(|head| {
+        let reference = head.trim().strip_prefix("ref: ")?;
+        git(&["rev-parse", "--git-path", reference])
+    });
+    let mut watched = vec![head];
+    match branch {
+        Some(path) if Path::new(&path).exists() => watched.push(path),
+        Some(_) => watched.extend(git(&["rev-parse", "--git-path", "packed-refs"])),
+        None => {}
+    }
+    watched
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    Some(stdout.trim().to_owned())
+}
+
+/// Formats seconds since the epoch as `YYYY-MM-DDTHH:MM:SSZ`
+pub(crate) fn rfc3339(secs: u64) -> String {
+    let (days, time) = (secs / 86_400, secs % 86_400);
+    // Howard Hinnant's civil_from_days, shifted so years start in March
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
@@ -0,0 +1,53 @@
+//! Support for the collection arms of [`serde_default`](crate::serde_default)
+use alloc::{ffi::CString, string::String, vec::Vec};
+use core::ffi::CStr;
+
+/// Owned form of a literal stored in a collection, so `"read"` becomes a `String` key
+pub trait OwnedLit {
+    type Owned;
+
+    fn into_owned(self) -> Self::Owned;
+}
+
+macro_rules! owned_as_is {
+    ($($kind:ty),* $(,)?) => {
+        $(
+            impl OwnedLit for $kind {
+                type Owned = $kind;
+
+                fn into_owned(self) -> Self::Owned {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+owned_as_is!(bool, char, f32, f64, usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
+impl OwnedLit for &'static str {
+    type Owned = String;
+
+    fn into_owned(self) -> Self::Owned {
+        String::from(self)
+    }
+}
+
+impl OwnedLit for &'static [u8] {
+    type Owned = Vec<u8>;
+
+    fn into_owned(self) -> Self::Owned {
+        Vec::from(self)
+    }
+}
+
+impl OwnedLit for &'static CStr {
+    type Owned = CString;
+
+    fn into_owned(self) -> Self::Owned {
+        CString::from(self)
+    }
+}
+
+/// Owned type of `T`, see [`OwnedLit`]
+pub type Owned<T> = <T as OwnedLit>::Owned;
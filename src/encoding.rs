@@ -0,0 +1,81 @@
+//! `const` decoders behind the `base64` and `hex` arms of [`serde_default`](crate::serde_default),
+//! the decoded length is computed first so the bytes can live in a `const` array
+use crate::net::hex_digit;
+
+/// Decoded length of a padded or unpadded standard base64 string
+pub const fn base64_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut len = bytes.len();
+    while len > 0 && bytes[len - 1] == b'=' {
+        len -= 1;
+    }
+    len * 3 / 4
+}
+
+/// Standard alphabet, `=` padding is optional but has to be complete when present
+pub const fn decode_base64<const N: usize>(text: &str) -> Option<[u8; N]> {
+    let bytes = text.as_bytes();
+    let mut len = bytes.len();
+    while len > 0 && bytes[len - 1] == b'=' {
+        len -= 1;
+    }
+    let padding = bytes.len() - len;
+    if len % 4 == 1
+        || padding > 2
+        || (padding > 0 && !bytes.len().is_multiple_of(4))
+        || len * 3 / 4 != N
+    {
+        return None;
+    }
+    let mut out = [0u8; N];
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    let mut i = 0;
+    let mut o = 0;
+    while i < len {
+        let value = match bytes[i] {
+            b'A'..=b'Z' => bytes[i] - b'A',
+            b'a'..=b'z' => bytes[i] - b'a' + 26,
+            b'0'..=b'9' => bytes[i] - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        acc = (acc << 6 | value as u32) & 0xfff;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out[o] = (acc >> bits) as u8;
+            o += 1;
+        }
+        i += 1;
+    }
+    // leftover bits of the last symbol have to be zero, otherwise there are two spellings of the same bytes
+    if acc & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+    Some(out)
+}
+
+/// Decoded length of a hex string
+pub const fn hex_len(text: &str) -> usize {
+    text.len() / 2
+}
+
+/// Upper or lower case digits, without a `0x` prefix
+pub const fn decode_hex<const N: usize>(text: &str) -> Option<[u8; N]> {
+    let bytes = text.as_bytes();
+    if bytes.len() != N * 2 {
+        return None;
+    }
+    let mut out = [0u8; N];
+    let mut o = 0;
+    while o < N {
+        match (hex_digit(bytes[o * 2]), hex_digit(bytes[o * 2 + 1])) {
+            (Some(high), Some(low)) => out[o] = high << 4 | low,
+            _ => return None,
+        }
+        o += 1;
+    }
+    Some(out)
+}
@@ -0,0 +1,51 @@
+//! Environment lookup behind `Name::from_env()` of [`SerdeDefault`](crate::SerdeDefault)
+use std::string::String;
+use std::{env, fmt};
+
+/// Error returned by `from_env()` when a variable is set but doesn't parse
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FromEnvError {
+    var: String,
+    value: Option<String>,
+}
+
+impl FromEnvError {
+    /// Name of the variable, like `APP_PORT`
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+
+    /// Value that didn't parse, `None` if it isn't unicode
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+impl fmt::Display for FromEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "invalid value of ${}: {value:?}", self.var),
+            None => write!(f, "${} isn't unicode", self.var),
+        }
+    }
+}
+
+impl std::error::Error for FromEnvError {}
+
+/// `T` parsed from the variable `name`, or `None` if it isn't set
+pub fn env_var<T: core::str::FromStr>(name: &str) -> Result<Option<T>, FromEnvError> {
+    match env::var(name) {
+        Ok(value) => match value.parse() {
+            Ok(parsed) => Ok(Some(parsed)),
+            Err(_) => Err(FromEnvError {
+                var: name.into(),
+                value: Some(value),
+            }),
+        },
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(env::VarError::NotUnicode(_)) => Err(FromEnvError {
+            var: name.into(),
+            value: None,
+        }),
+    }
+}
@@ -0,0 +1,34 @@
+//! Identifier defaults
+
+/// Nil [`Uuid`](uuid::Uuid) default
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert!(default_uuid_nil().is_nil());
+/// ```
+#[cfg(feature = "uuid")]
+pub const fn default_uuid_nil() -> uuid::Uuid {
+    uuid::Uuid::nil()
+}
+
+/// Fresh random v4 [`Uuid`](uuid::Uuid) every time the field is missing
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_ne!(default_uuid_v4(), default_uuid_v4());
+/// ```
+#[cfg(feature = "uuid-v4")]
+pub fn default_uuid_v4() -> uuid::Uuid {
+    uuid::Uuid::new_v4()
+}
+
+/// Fresh [`Ulid`](ulid::Ulid) every time the field is missing
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_ne!(default_ulid_new(), default_ulid_new());
+/// ```
+#[cfg(all(feature = "ulid", feature = "std"))]
+pub fn default_ulid_new() -> ulid::Ulid {
+    ulid::Ulid::new()
+}
@@ -0,0 +1,135 @@
+//! JSON helpers behind the `#[serde_default(json)]` methods of [`SerdeDefault`](crate::SerdeDefault)
+use alloc::{string::String, vec::Vec};
+use serde_json::{Map, Value};
+
+/// Members of `value` that differ from `default`, objects are compared member by member
+/// and anything else as a whole
+pub fn diff(value: Value, default: &Value) -> Value {
+    changed(value, default).unwrap_or_else(|| Value::Object(Map::new()))
+}
+
+fn changed(value: Value, default: &Value) -> Option<Value> {
+    match (value, default) {
+        (value, default) if value == *default => None,
+        (Value::Object(members), Value::Object(defaults)) => {
+            let members: Map<_, _> = members
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    let value = match defaults.get(&key) {
+                        Some(default) => changed(value, default)?,
+                        None => value,
+                    };
+                    Some((key, value))
+                })
+                .collect();
+            (!members.is_empty()).then_some(Value::Object(members))
+        }
+        (value, _) => Some(value),
+    }
+}
+
+/// Applies a JSON Merge Patch (RFC 7386), `null` members remove what they name
+pub fn merge_patch(target: &mut Value, patch: Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch;
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Value::Object(members) = target else {
+        unreachable!()
+    };
+    for (key, value) in patch {
+        if value.is_null() {
+            members.remove(&key);
+        } else {
+            merge_patch(members.entry(key).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// JSON Merge Patch (RFC 7386) turning `source` into `target`
+pub fn create_merge_patch(source: &Value, target: Value) -> Value {
+    match (source, target) {
+        (Value::Object(sources), Value::Object(targets)) => {
+            let mut patch: Map<_, _> = sources
+                .keys()
+                .filter(|key| !targets.contains_key(*key))
+                .map(|key| (key.clone(), Value::Null))
+                .collect();
+            for (key, value) in targets {
+                match sources.get(&key) {
+                    Some(source) if *source == value => {}
+                    Some(source) => {
+                        patch.insert(key, create_merge_patch(source, value));
+                    }
+                    None => {
+                        patch.insert(key, value);
+                    }
+                }
+            }
+            Value::Object(patch)
+        }
+        (_, target) => target,
+    }
+}
+
+/// JSON Patch (RFC 6902) turning `source` into `target`, objects are compared member by member
+/// and anything else is replaced as a whole
+pub fn create_json_patch(source: &Value, target: Value) -> Value {
+    let mut operations = Vec::new();
+    json_patch_operations(&mut operations, &mut String::new(), source, target);
+    Value::Array(operations)
+}
+
+fn json_patch_operations(
+    operations: &mut Vec<Value>,
+    path: &mut String,
+    source: &Value,
+    target: Value,
+) {
+    match (source, target) {
+        (source, target) if *source == target => {}
+        (Value::Object(sources), Value::Object(mut targets)) => {
+            for (key, source) in sources {
+                let len = path.len();
+                push_pointer_token(path, key);
+                match targets.remove(key) {
+                    Some(target) => json_patch_operations(operations, path, source, target),
+                    None => operations.push(operation("remove", path, None)),
+                }
+                path.truncate(len);
+            }
+            for (key, target) in targets {
+                let len = path.len();
+                push_pointer_token(path, &key);
+                operations.push(operation("add", path, Some(target)));
+                path.truncate(len);
+            }
+        }
+        (_, target) => operations.push(operation("replace", path, Some(target))),
+    }
+}
+
+/// Appends `/key` with `~` and `/` escaped as JSON Pointer (RFC 6901) requires
+fn push_pointer_token(path: &mut String, key: &str) {
+    path.push('/');
+    for c in key.chars() {
+        match c {
+            '~' => path.push_str("~0"),
+            '/' => path.push_str("~1"),
+            c => path.push(c),
+        }
+    }
+}
+
+fn operation(op: &str, path: &str, value: Option<Value>) -> Value {
+    let mut operation = Map::new();
+    operation.insert("op".into(), op.into());
+    operation.insert("path".into(), path.into());
+    if let Some(value) = value {
+        operation.insert("value".into(), value);
+    }
+    Value::Object(operation)
+}
@@ -0,0 +1,63 @@
+//! `const` parsers behind the `log_level` and `tracing_level` arms of [`serde_default`](crate::serde_default),
+//! names are case insensitive just like `FromStr` in `log` and `tracing`
+
+/// `off`, `error`, `warn`, `info`, `debug` or `trace`
+#[cfg(feature = "log")]
+pub const fn parse_level_filter(text: &str) -> Option<log::LevelFilter> {
+    use log::LevelFilter;
+
+    if eq_ignore_case(text, "off") {
+        return Some(LevelFilter::Off);
+    }
+    match level_index(text) {
+        Some(0) => Some(LevelFilter::Error),
+        Some(1) => Some(LevelFilter::Warn),
+        Some(2) => Some(LevelFilter::Info),
+        Some(3) => Some(LevelFilter::Debug),
+        Some(_) => Some(LevelFilter::Trace),
+        None => None,
+    }
+}
+
+/// `error`, `warn`, `info`, `debug` or `trace`
+#[cfg(feature = "tracing")]
+pub const fn parse_tracing_level(text: &str) -> Option<tracing::Level> {
+    use tracing::Level;
+
+    match level_index(text) {
+        Some(0) => Some(Level::ERROR),
+        Some(1) => Some(Level::WARN),
+        Some(2) => Some(Level::INFO),
+        Some(3) => Some(Level::DEBUG),
+        Some(_) => Some(Level::TRACE),
+        None => None,
+    }
+}
+
+const LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+const fn level_index(text: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < LEVELS.len() {
+        if eq_ignore_case(text, LEVELS[i]) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+const fn eq_ignore_case(text: &str, lower: &str) -> bool {
+    let (text, lower) = (text.as_bytes(), lower.as_bytes());
+    if text.len() != lower.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < text.len() {
+        if text[i].to_ascii_lowercase() != lower[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -7,11 +7,60 @@
 //!
 //! # Kudos
 //! - JohnTheCoolingFan posted this solution, I just made it available as crate and a macro that
-//!     helps to generate another const generic function for any const generic type.
+//!   helps to generate another const generic function for any const generic type.
 //! - bytedream         made a more powerful version of it, and although I still see const generic approach as more readable,
-//!     I have to admit that for strings it's superior, hence - included under the feature
+//!   I have to admit that for strings it's superior, hence - included under the feature
 //!
 //!
+//! # Features
+//! - `std` (default) - enables `alloc` and helpers that need the standard library
+//! - `alloc` - owned defaults like `String`
+//! - `inline` - re-exports [serde_inline_default](https://docs.rs/serde-inline-default)
+//! - `humantime` - `serde_default!(name, duration "5m30s")` parsed at compile time
+//! - `chrono` - `serde_default!(name, date "2024-01-01")` and `datetime "2024-01-01T00:00:00Z"`
+//! - `time` - `serde_default!(name, time::date "2024-01-01")`, `time::time "12:00:00"` and `time::datetime "2024-01-01T00:00:00Z"`
+//! - `jiff` - `serde_default!(name, jiff::timestamp "2024-01-01T00:00:00Z")`, `jiff::zoned "..."` and `jiff::span "1h 30m"`
+//! - `chrono-tz` - `serde_default!(name, timezone "Europe/Berlin")`
+//! - `uuid` - `default_uuid_nil()` and `serde_default!(name, uuid "67e55044-10b1-426f-9247-bb680e5fe0c8")`
+//! - `uuid-v4` - `default_uuid_v4()` generating a fresh random uuid
+//! - `ulid` - `default_ulid_new()` (with `std`) and `serde_default!(name, ulid "01ARZ3NDEKTSV4RRFFQ69G5FAV")`
+//! - `ipnet` - `serde_default!(name, cidr "10.0.0.0/8")`
+//! - `dirs` - `default_config_dir()`, `default_cache_dir()`, `default_data_dir()` and `serde_default!(name, config_dir "my-app")`
+//! - `url` - `serde_default!(name, url "https://api.example.com/v1")`
+//! - `email` - `serde_default!(name, email "admin@example.com")`
+//! - `decimal` - `serde_default!(name, decimal "0.015")` producing `rust_decimal::Decimal`
+//! - `bigdecimal` - `serde_default!(name, bigdecimal "123.456")` producing `bigdecimal::BigDecimal`
+//! - `num-bigint` - `serde_default!(name, bigint "-1")` and `biguint "1"` for integers past `u128`
+//! - `num-rational` - `serde_default!(name, ratio "3/4")` producing a reduced `num_rational::Ratio<i64>`
+//! - `ordered-float` - `serde_default!(name, ordered 0.5)` and `not_nan 0.5` rejecting NaN at compile time
+//! - `indexmap` - `serde_default!(name, index_map { "a" => 1 })` and `index_set ["a"]` keeping insertion order
+//! - `smallvec` - `serde_default!(name, smallvec<4> [1u16, 2])` producing `SmallVec<[u16; 4]>`
+//! - `arrayvec` - `serde_default!(name, arrayvec<8> [1u16, 2])` producing `ArrayVec<u16, 8>`, checked to fit at compile time
+//! - `nonempty` - `serde_default!(name, nonempty ["a", "b"])` producing `NonEmpty<String>`, an empty list is rejected
+//! - `bytes` - `serde_default!(name, bytes b"hello")` and `bytes [1, 2]` producing a static `bytes::Bytes`
+//! - `json` - `serde_default!(name, json!({"retries": 3}))` producing `serde_json::Value`, `#[serde_default(json)]` adds `defaults_json()` to the derive
+//! - `yaml` - `serde_default!(name, yaml "app: web")` producing `serde_yaml::Value`, validated at compile time
+//! - `either` - `serde_default!(name, Either<u32, String> = Left(10))` producing `either::Either`
+//! - `heapless` - `serde_default!(name, heapless::String<16> = "boot")` and `heapless::Vec<u8, 4> = [1, 2]`, checked to fit at compile time
+//! - `smol_str` - `serde_default!(name, SmolStr = "eu-west-1")` producing a static `smol_str::SmolStr`
+//! - `compact_str` - `serde_default!(name, CompactString = "eu-west-1")` producing a static `compact_str::CompactString`
+//! - `bitflags` - `serde_default!(name, Flags::READ | Flags::WRITE)` for `bitflags` types
+//! - `regex` - `serde_default!(name, regex r"^\w+$")` validated at compile time and compiled once
+//! - `cron` - `serde_default!(name, cron "0 0 * * * *")` producing a `cron::Schedule` validated at compile time
+//! - `log` - `serde_default!(name, log_level "info")` producing `log::LevelFilter`
+//! - `tracing` - `serde_default!(name, tracing_level "info")` producing `tracing::Level`
+//! - `http` - `serde_default!(name, status 404)`, `method "GET"` and `header_value "..."` validated at compile time
+//! - `mime` - `serde_default!(name, mime "application/json")` producing `mime::Mime` validated at compile time
+//! - `money` - `serde_default!(name, money "19.99 USD")` producing the minimal `Money` type
+//! - `langid` - `serde_default!(name, lang "en-US")` producing `unic_langid::LanguageIdentifier` validated at compile time
+//! - `secrecy` - `serde_default!(name, SecretString = "change-me")` producing `secrecy::SecretString`
+//! - `glam` - `serde_default!(name, glam::Vec3 = [0.0, -9.81, 0.0])` for any `glam` vector
+//! - `mint` - `serde_default!(name, mint::Vector3<f32> = [0.0, 1.0, 0.0])` for any `mint` vector or point
+//! - `uom` - `serde_default!(name, uom Length = 2.5 meter)` for `f64` SI quantities
+//! - `build` - `build::emit()` for build scripts and `serde_default_build!()` generating `default_build_git_sha()` and `default_build_timestamp()`
+//! - `nightly` - `default_str::<"hello">()` and `ConstParamTy` enums with `serde_default!(Level)` on a nightly compiler
+//! - `file` - `#[serde_defaults(file = "defaults.toml")]` taking field defaults from a TOML or JSON file at compile time
+//!
 //! # Example
 //! ```rust
 //!     use serde_default_utils::*;
@@ -93,16 +142,587 @@
 //!
 //! ```
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "nightly", feature(adt_const_params, unsized_const_params))]
+#![cfg_attr(feature = "nightly", allow(incomplete_features))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+extern crate self as serde_default_utils;
 
 #[cfg(feature = "inline")]
 pub use serde_inline_default::serde_inline_default;
 
+/// Checks the functions named in `#[serde(default = "...")]` and `skip_serializing_if = "..."`
+/// at compile time, so a typo or a wrong return type fails right at the attribute
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serde_default_utils::*;
+///
+/// #[check_defaults]
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(default = "default_u16::<8080>", skip_serializing_if = "is_default_u16::<8080>")]
+///     port: u16,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[check_defaults]
+/// #[derive(Deserialize)]
+/// struct Config {
+///     // returns u32, not u16
+///     #[serde(default = "default_u32::<8080>")]
+///     port: u16,
+/// }
+/// ```
+pub use serde_default_utils_macros::check_defaults;
+
+/// Derives `Default` from `#[default(...)]` on fields and generates a `default_<field>()` function
+/// for each of them, so serde and `Default` share the same values. Fields without one call their
+/// `#[serde(default = "...")]` function or fall back to their type's `Default`, string literals turn into `String` fields
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault, Deserialize, Debug, PartialEq)]
+/// struct Server {
+///     #[default(8080)]
+///     #[serde(default = "Server::default_port")]
+///     port: u16,
+///     #[default("0.0.0.0")]
+///     #[serde(default = "Server::default_host")]
+///     host: String,
+///     #[serde(default)]
+///     verbose: bool,
+/// }
+///
+/// let server: Server = serde_json::from_str("{}").unwrap();
+/// assert_eq!(server, Server::default());
+/// assert_eq!(server.host, "0.0.0.0");
+///
+/// // or `#[serde(default)]` on the struct to fill missing fields from `Server::default()`
+/// ```
+///
+/// With `#[serde_default(const)]` every field needs a value that can be built in a `const` context,
+/// the functions become `const fn` and `Name::DEFAULT` can be used in statics or compared against
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault, Deserialize, PartialEq)]
+/// #[serde_default(const)]
+/// #[serde(default)]
+/// struct Limits {
+///     #[default(100)]
+///     requests: u32,
+///     #[default("eu-west-1")]
+///     region: &'static str,
+/// }
+///
+/// static LIMITS: Limits = Limits::DEFAULT;
+///
+/// let limits: Limits = serde_json::from_str("{}").unwrap();
+/// assert!(limits == LIMITS);
+/// ```
+///
+/// `#[serde_default(is_default)]` adds `Name::is_default_<field>(&value)` for every field,
+/// so leaving out whatever equals its default is a matter of `skip_serializing_if`
+///
+/// ```rust
+/// use serde::Serialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault, Serialize)]
+/// #[serde_default(is_default)]
+/// struct Server {
+///     #[default(8080)]
+///     #[serde(skip_serializing_if = "Server::is_default_port")]
+///     port: u16,
+///     #[serde(skip_serializing_if = "Server::is_default_verbose")]
+///     verbose: bool,
+/// }
+///
+/// let server = Server { port: 9090, ..Server::default() };
+/// assert_eq!(serde_json::to_string(&server).unwrap(), r#"{"port":9090}"#);
+/// ```
+///
+/// `#[serde_default(partial)]` adds `NamePartial` with every field optional and `Name::from_partial`
+/// filling the gaps from the defaults, `partial(derive(...))` derives traits on it as well
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault, Deserialize)]
+/// #[serde_default(partial(derive(Debug, Deserialize)))]
+/// #[serde(rename_all = "camelCase")]
+/// struct Server {
+///     #[default(8080)]
+///     port: u16,
+///     #[default(4)]
+///     worker_count: u8,
+/// }
+///
+/// let patch: ServerPartial = serde_json::from_str(r#"{"workerCount": 8}"#).unwrap();
+/// let server = Server::from_partial(patch);
+/// assert_eq!((server.port, server.worker_count), (8080, 8));
+/// ```
+///
+/// `#[serde_default(merge)]` adds `Name::merge(&mut self, other)` taking every field of `other`
+/// that isn't at its default, for a base config with overrides on top. Together with `partial`
+/// there is `Name::merge_partial` as well, taking every field that is set
+///
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault)]
+/// #[serde_default(merge)]
+/// struct Server {
+///     #[default(8080)]
+///     port: u16,
+///     #[default("0.0.0.0")]
+///     host: String,
+/// }
+///
+/// let mut base = Server { port: 9090, ..Server::default() };
+/// base.merge(Server { host: "127.0.0.1".into(), ..Server::default() });
+/// assert_eq!((base.port, base.host.as_str()), (9090, "127.0.0.1"));
+/// ```
+///
+/// `#[serde_default(env = "VAR", fallback = value)]` on a field reads `VAR` first, parsed with `FromStr`,
+/// and takes the fallback only when it isn't set. Without `fallback` the field falls back to `#[default(...)]`
+/// or its type's `Default`, a variable that doesn't parse panics instead of being skipped silently
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault, Deserialize)]
+/// struct Server {
+///     #[serde_default(env = "SERVER_PORT", fallback = 8080)]
+///     #[serde(default = "Server::default_port")]
+///     port: u16,
+/// }
+///
+/// std::env::set_var("SERVER_PORT", "9090");
+/// let server: Server = serde_json::from_str("{}").unwrap();
+/// assert_eq!(server.port, 9090);
+/// # std::env::remove_var("SERVER_PORT");
+/// ```
+///
+/// `#[serde_default(env_prefix = "APP_")]` adds `Name::from_env()` reading every field from `APP_<FIELD>`,
+/// the defaults fill in the variables that aren't set. `#[serde_default(nested)]` on a field reads
+/// `APP_<FIELD>__<NESTED>` into a struct with its own `env_prefix`, `skip_env` leaves a field at its default
+///
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault)]
+/// #[serde_default(env_prefix = "")]
+/// struct Database {
+///     #[default("localhost")]
+///     host: String,
+///     #[default(5432)]
+///     port: u16,
+/// }
+///
+/// #[derive(SerdeDefault)]
+/// #[serde_default(env_prefix = "APP_")]
+/// struct Config {
+///     #[default(4)]
+///     workers: u8,
+///     #[serde_default(nested)]
+///     database: Database,
+/// }
+///
+/// std::env::set_var("APP_WORKERS", "8");
+/// std::env::set_var("APP_DATABASE__HOST", "db.internal");
+/// let config = Config::from_env().unwrap();
+/// assert_eq!((config.workers, config.database.host.as_str(), config.database.port), (8, "db.internal", 5432));
+///
+/// std::env::set_var("APP_WORKERS", "many");
+/// assert_eq!(Config::from_env().err().unwrap().to_string(), r#"invalid value of $APP_WORKERS: "many""#);
+/// ```
+///
+/// With the `json` feature `#[serde_default(json)]` adds `Name::defaults_json()`,
+/// every default as a JSON object for tooling that shows the effective configuration,
+/// `diff_from_default()` keeping only the fields that were changed, and JSON Merge Patch (RFC 7386)
+/// support with `apply_merge_patch(patch)` and `merge_patch_from_default()` for syncing changes.
+/// `json_patch_from_default()` lists the same changes as JSON Patch (RFC 6902) operations for audit trails
+///
+/// ```rust
+/// # #[cfg(feature = "json")]
+/// # {
+/// use serde::{Deserialize, Serialize};
+/// use serde_default_utils::*;
+///
+/// #[derive(SerdeDefault, Serialize, Deserialize)]
+/// #[serde_default(json)]
+/// struct Server {
+///     #[default(8080)]
+///     port: u16,
+///     #[serde(rename = "bind")]
+///     #[default("0.0.0.0")]
+///     host: String,
+/// }
+///
+/// assert_eq!(
+///     Server::defaults_json(),
+///     serde_json::json!({"port": 8080, "bind": "0.0.0.0"})
+/// );
+///
+/// let server = Server { port: 9090, ..Server::default() };
+/// assert_eq!(server.diff_from_default(), serde_json::json!({"port": 9090}));
+///
+/// let mut synced = Server::default();
+/// synced.apply_merge_patch(server.merge_patch_from_default()).unwrap();
+/// assert_eq!(synced.port, 9090);
+///
+/// assert_eq!(
+///     server.json_patch_from_default(),
+///     serde_json::json!([{"op": "replace", "path": "/port", "value": 9090}])
+/// );
+/// # }
+/// ```
+pub use serde_default_utils_macros::SerdeDefault;
+
+/// Wires the serde default of every field of a struct in one line, put it above `#[derive(Deserialize)]`
+///
+/// - fields with `#[default(...)]` or `#[serde_default(env = "...")]` get `#[serde(default = "Name::default_<field>")]`
+/// - with `consts = module` the remaining fields take `module::<FIELD>`
+/// - otherwise the field falls back to its type's `Default` with `#[serde(default)]`
+///
+/// Fields that already have a serde `default` are left as they are, which is also how a field opts out
+/// of `consts`. [`SerdeDefault`] is derived when needed, so `Name::default()` matches what serde fills in,
+/// calling those serde `default` functions too, and `env_prefix = "APP_"` is passed on to it for `Name::from_env()`
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// mod defaults {
+///     pub const PORT: u16 = 8080;
+///     pub const HOST: &str = "0.0.0.0";
+/// }
+///
+/// #[serde_defaults(consts = defaults)]
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// struct Server {
+///     port: u16,
+///     host: String,
+///     #[default(4)]
+///     workers: u8,
+///     #[serde(default)]
+///     verbose: bool,
+/// }
+///
+/// let server: Server = serde_json::from_str("{}").unwrap();
+/// assert_eq!(server, Server::default());
+/// assert_eq!((server.port, server.host.as_str(), server.workers), (8080, "0.0.0.0", 4));
+/// ```
+///
+/// With the `file` feature `file = "defaults.toml"` takes the defaults from a `.toml` or `.json` file
+/// next to `Cargo.toml`, read at compile time. Strings, numbers and booleans are checked against
+/// the field types like `#[default(...)]`, arrays and tables are deserialized from JSON when the
+/// default is built. Inline `#[default(...)]` wins over the file, which wins over `consts`,
+/// and a key that isn't a field is an error
+///
+/// ```rust
+/// # #[cfg(feature = "file")]
+/// # {
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[serde_defaults]
+/// #[derive(Deserialize)]
+/// struct Tls {
+///     enabled: bool,
+///     cert: String,
+/// }
+///
+/// // port = 8080
+/// // host = "0.0.0.0"
+/// // ...
+/// // [tls]
+/// // enabled = true
+/// // cert = "a.pem"
+/// #[serde_defaults(file = "testdata/defaults.toml")]
+/// #[derive(Deserialize)]
+/// struct Server {
+///     port: u16,
+///     host: String,
+///     ratio: f32,
+///     offset: i8,
+///     tags: Vec<String>,
+///     tls: Tls,
+/// }
+///
+/// let server: Server = serde_json::from_str("{}").unwrap();
+/// assert_eq!((server.port, server.host.as_str(), server.tls.cert.as_str()), (8080, "0.0.0.0", "a.pem"));
+/// # }
+/// ```
+///
+/// TOML datetimes have no field type to become and fail to compile, quote them as strings instead
+/// ```compile_fail
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[serde_defaults]
+/// #[derive(Deserialize)]
+/// struct Tls {
+///     expires: String,
+/// }
+///
+/// // [tls]
+/// // expires = 2030-01-01T00:00:00Z
+/// #[serde_defaults(file = "testdata/datetime.toml")]
+/// #[derive(Deserialize)]
+/// struct Server {
+///     tls: Tls,
+/// }
+/// ```
+pub use serde_default_utils_macros::serde_defaults;
+
+#[cfg(feature = "build")]
+pub mod build;
+mod encoding;
+#[cfg(feature = "std")]
+mod env;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod levels;
+mod lit;
+#[cfg(feature = "money")]
+mod money;
+mod net;
+mod num;
+pub mod unknown_as_default;
+
+#[cfg(feature = "alloc")]
+mod collections;
+
+#[cfg(feature = "std")]
+mod paths;
+#[cfg(feature = "std")]
+pub use paths::*;
+
+#[cfg(any(feature = "uuid", all(feature = "ulid", feature = "std")))]
+mod ids;
+#[cfg(any(feature = "uuid", all(feature = "ulid", feature = "std")))]
+pub use ids::*;
+
+#[cfg(feature = "money")]
+pub use money::{Money, ParseMoneyError};
+
+#[cfg(feature = "std")]
+pub use env::FromEnvError;
+
+/// Re-exports used by the code [`serde_default`] expands to, not a public API
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "std")]
+    pub use crate::env::env_var;
+    pub use crate::lit::LitInto;
+    #[cfg(feature = "alloc")]
+    pub use alloc::{boxed::Box, ffi::CString, rc::Rc, string::String, sync::Arc, vec, vec::Vec};
+    pub use paste::paste;
+    pub use serde;
+    #[cfg(feature = "std")]
+    pub use std::ffi::{OsStr, OsString};
+    #[cfg(feature = "std")]
+    pub use std::path::{Path, PathBuf};
+
+    #[cfg(feature = "ipnet")]
+    pub use crate::net::parse_ipnet;
+    pub use crate::net::{parse_ip, parse_ipv4, parse_ipv6, parse_socket_addr};
+    #[cfg(feature = "ipnet")]
+    pub use ipnet;
+    #[cfg(feature = "humantime")]
+    pub use serde_default_utils_macros::duration;
+
+    #[cfg(feature = "chrono")]
+    pub use chrono;
+    #[cfg(feature = "chrono")]
+    pub use serde_default_utils_macros::{chrono_date, chrono_datetime};
+
+    #[cfg(feature = "time")]
+    pub use serde_default_utils_macros::{time_date, time_datetime, time_time};
+    #[cfg(feature = "time")]
+    pub use time;
+
+    #[cfg(feature = "jiff")]
+    pub use jiff;
+    #[cfg(feature = "jiff")]
+    pub use serde_default_utils_macros::{jiff_span, jiff_timestamp, jiff_zoned};
+
+    #[cfg(feature = "chrono-tz")]
+    pub use chrono_tz;
+    #[cfg(feature = "chrono-tz")]
+    pub use serde_default_utils_macros::timezone;
+
+    #[cfg(feature = "uuid")]
+    pub use uuid;
+
+    #[cfg(feature = "ulid")]
+    pub use ulid;
+
+    #[cfg(feature = "url")]
+    pub use ::url;
+    #[cfg(feature = "url")]
+    pub use serde_default_utils_macros::url;
+
+    #[cfg(feature = "email")]
+    pub use email_address;
+    #[cfg(feature = "email")]
+    pub use serde_default_utils_macros::email;
+
+    #[cfg(feature = "decimal")]
+    pub use crate::num::parse_rust_decimal;
+    #[cfg(feature = "decimal")]
+    pub use rust_decimal;
+
+    #[cfg(feature = "bigdecimal")]
+    pub use ::bigdecimal;
+    #[cfg(feature = "bigdecimal")]
+    pub use serde_default_utils_macros::bigdecimal;
+
+    #[cfg(feature = "num-rational")]
+    pub use crate::num::parse_ratio;
+    #[cfg(feature = "num-bigint")]
+    pub use num_bigint;
+    #[cfg(feature = "num-rational")]
+    pub use num_rational;
+    #[cfg(feature = "num-bigint")]
+    pub use serde_default_utils_macros::{bigint, biguint};
+
+    #[cfg(feature = "ordered-float")]
+    pub use ordered_float;
+
+    pub use serde_default_utils_macros::lit_ty;
+
+    #[cfg(feature = "alloc")]
+    pub use crate::collections::{Owned, OwnedLit};
+    #[cfg(feature = "alloc")]
+    pub use alloc::collections::{BTreeMap, BTreeSet};
+    #[cfg(feature = "std")]
+    pub use std::collections::{HashMap, HashSet};
+
+    #[cfg(feature = "indexmap")]
+    pub use indexmap;
+
+    #[cfg(feature = "arrayvec")]
+    pub use arrayvec;
+    #[cfg(feature = "smallvec")]
+    pub use smallvec;
+
+    #[cfg(feature = "nonempty")]
+    pub use nonempty;
+
+    #[cfg(feature = "bytes")]
+    pub use bytes;
+
+    pub use crate::encoding::{base64_len, decode_base64, decode_hex, hex_len};
+
+    #[cfg(feature = "json")]
+    pub use crate::json::{
+        create_json_patch as json_create_patch, create_merge_patch as json_create_merge_patch,
+        diff as json_diff, merge_patch as json_merge_patch,
+    };
+    #[cfg(feature = "json")]
+    pub use serde_json;
+
+    #[cfg(feature = "yaml")]
+    pub use serde_default_utils_macros::yaml;
+    #[cfg(feature = "yaml")]
+    pub use serde_yaml;
+
+    #[cfg(feature = "either")]
+    pub use either;
+
+    #[cfg(feature = "heapless")]
+    pub use heapless;
+
+    #[cfg(feature = "compact_str")]
+    pub use compact_str;
+    #[cfg(feature = "smol_str")]
+    pub use smol_str;
+
+    #[cfg(feature = "bitflags")]
+    pub use bitflags;
+
+    #[cfg(feature = "regex")]
+    pub use ::regex;
+    #[cfg(feature = "regex")]
+    pub use serde_default_utils_macros::regex;
+    #[cfg(feature = "std")]
+    pub use std::sync::LazyLock;
+
+    #[cfg(feature = "cron")]
+    pub use ::cron;
+    #[cfg(feature = "cron")]
+    pub use serde_default_utils_macros::cron;
+
+    #[cfg(feature = "log")]
+    pub use crate::levels::parse_level_filter;
+    #[cfg(feature = "tracing")]
+    pub use crate::levels::parse_tracing_level;
+    #[cfg(feature = "log")]
+    pub use log;
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+
+    #[cfg(feature = "http")]
+    pub use http;
+    #[cfg(feature = "http")]
+    pub use serde_default_utils_macros::http_method;
+
+    #[cfg(feature = "mime")]
+    pub use ::mime;
+    #[cfg(feature = "mime")]
+    pub use serde_default_utils_macros::mime;
+
+    pub use crate::num::parse_byte_size;
+
+    pub use crate::num::parse_percent;
+
+    #[cfg(feature = "langid")]
+    pub use serde_default_utils_macros::langid;
+    #[cfg(feature = "langid")]
+    pub use unic_langid;
+
+    #[cfg(feature = "secrecy")]
+    pub use secrecy;
+
+    #[cfg(feature = "glam")]
+    pub use glam;
+    #[cfg(feature = "mint")]
+    pub use mint;
+
+    #[cfg(feature = "uom")]
+    pub use uom;
+
+    #[cfg(all(feature = "std", unix))]
+    pub use std::{fs::Permissions, os::unix::fs::PermissionsExt};
+
+    pub use serde_default_utils_macros::{doc_value, mod_fields};
+
+    pub use crate::num::parse_integer;
+}
+
 /// Generates a function for a type provided or a custom default function
 /// This is not supposed to be used outside since const generic parameter approach
 /// is [pretty limited](https://doc.rust-lang.org/reference/items/generics.html#const-generics) at the moment
 ///
 /// # Limitations
-/// Slices are limited to only `&'static [u8]` and parcing it from JSON
+/// Slice elements are typed by the first suffixed literal, unsuffixed integers are `u8`.
+/// Slices are `&'static` and parcing it from JSON
 /// using `serde_json::from_str`` will not work, only `serde_json::from_str`.
 ///
 /// # Output
@@ -114,148 +734,4296 @@ pub use serde_inline_default::serde_inline_default;
 /// // pub const fn default_u8<const V: u8>() -> u8 {
 /// //     V
 /// // }
+/// // pub const fn default_some_u8<const V: u8>() -> Option<u8> {
+/// //     Some(V)
+/// // }
+/// // pub const fn default_array_u8<const V: u8, const N: usize>() -> [u8; N] {
+/// //     [V; N]
+/// // }
+/// // pub fn is_default_u8<const V: u8>(value: &u8) -> bool {
+/// //     *value == V
+/// // }
+/// // and `is_default_some_u8`, `is_default_array_u8` to pair with `skip_serializing_if`
 /// serde_default!(u8);
 ///
+/// // Several types at once, generates the same for each of them
+/// mod ports {
+///     pub type Port = u16;
+///     serde_default_utils::serde_default!(Port, bool, char);
+/// }
+/// assert_eq!(ports::default_port::<8080>(), 8080);
+///
+/// // Every generated function is documented with the value it was given, without the type or keyword,
+/// // so `port: u16 = 8080` gets "Default value: `8080`" and `timeout, duration "5s"` "Default value: `"5s"`"
 /// // Generates
+/// // /// Default value: `"hey"`
 /// // pub const fn default_hey() -> &'static ::core::primitive::str {
 /// //     "hey"
 /// // }
 /// serde_default!(hey, "hey");
 ///
-/// // !Experimental!
-/// // (not sure if it's even useful, but this allows you to store raw bytes)
+/// // Any type with a const expression, `:` can be used in place of `,` in every arm
 /// // Generates
-/// // pub const fn default_arr() -> &'static [::core::primitive::u8] {
-/// //     &[1,2,3,4,5]
+/// // pub const fn default_session_timeout() -> u64 {
+/// //     30 * 60
 /// // }
-/// serde_default!(arr, &[1,2,3,4,5]);
+/// serde_default!(session_timeout: u64 = 30 * 60);
+/// serde_default!(retry_backoff, [u16; 3] = [100, 200, 400]);
 ///
-/// assert!(default_u8::<6>() == 6u8);
-/// assert_eq!(default_hey(), "hey");
-/// assert_eq!(default_arr(), &[1,2,3,4,5]);
+/// // Parses an integer from a string at compile time, `_` separators and `0x`/`0o`/`0b` prefixes
+/// // are allowed and a value that doesn't fit the type fails to compile
+/// // Generates
+/// // pub const fn default_max_items() -> u64 {
+/// //     1_000_000
+/// // }
+/// serde_default!(max_items, parse u64 "1_000_000");
+/// serde_default!(mask, parse u32 "0xffff_0000");
 ///
-/// ```
-#[macro_export]
-macro_rules! serde_default {
-    ($kind:ty) => {
-        ::paste::paste! {
-            pub const fn [<default_$kind:lower>]<const V: $kind>() -> $kind {
-                V
-            }
-        }
-    };
-    ($name:ident,$text:literal) => {
-        ::paste::paste! {
-            pub const fn [<default_$name:lower>]() -> &'static ::core::primitive::str {
-                $text
-            }
-        }
-    };
-    ($name:ident, &[ $($value:expr),* $(,)? ]) => {
-        ::paste::paste! {
-            pub const fn [<default_$name:lower>]() -> &'static [::core::primitive::u8] {
-                &[$($value,)*]
-            }
-        }
-    };
-}
-
-serde_default!(bool);
-serde_default!(char);
-serde_default!(usize);
-serde_default!(u8);
-serde_default!(u16);
-serde_default!(u32);
-serde_default!(u64);
-serde_default!(u128);
-serde_default!(isize);
-serde_default!(i8);
-serde_default!(i16);
-serde_default!(i32);
-serde_default!(i64);
-serde_default!(i128);
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use expect_test::expect;
-    use serde::{Deserialize, Serialize};
-    #[cfg(feature = "inline")]
-    use serde_inline_default::serde_inline_default;
-
-    serde_default!(hey, "You matter");
-
-    #[derive(Serialize, Deserialize, Default)]
-    struct Config {
-        #[serde(default = "default_bool::<true>")]
-        yes_or_no: bool,
-        #[serde(default = "default_i32::<-3>")]
-        delta: i32,
-        #[serde(default = "default_u32::<7>")]
-        max: u32,
-        #[serde(default = "default_char::<'☀'>")]
-        delimeter: char,
-        #[serde(default = "default_hey")]
-        motto: &'static str,
-    }
-
-    #[cfg(feature = "inline")]
-    #[serde_inline_default]
-    #[derive(Serialize, Deserialize, Default)]
-    struct InlineConfig {
-        #[serde_inline_default("You matter even more".to_string())]
-        inline_motto: String,
-        #[serde_inline_default(vec!["You".into(), "matter".into(), "even".into(), "more".into()])]
-        slice: Vec<String>,
-        #[serde_inline_default(vec![98712, 12346, 129389, 102937])]
-        slice_u64: Vec<u64>,
-    }
-
-    const JSON: &str = r#"{
-            "yes_or_no":false,
-            "max":60,
-            "delta":-77,
-            "delimeter":"☀",
-            "motto":"I matter"
-        }"#;
-    #[cfg(feature = "inline")]
-    const INLINE_JSON: &str = r#"{
-            "inline_motto":"I matter even more",
-            "slice":["I", "matter", "even", "more"],
-            "slice_u64":[9000, 8000, 10000, 7000]
-        }"#;
-    const EMPTY_JSON: &str = r#"{}"#;
-
-    #[test]
-    fn deserialization_works() {
-        let config: Config = serde_json::from_str(JSON).unwrap();
-        let s = serde_json::to_string(&config).unwrap();
-        expect![[r#"{"yes_or_no":false,"delta":-77,"max":60,"delimeter":"☀","motto":"I matter"}"#]]
-            .assert_eq(&s);
-        let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
-        let s = serde_json::to_string(&config).unwrap();
-        expect![[r#"{"yes_or_no":true,"delta":-3,"max":7,"delimeter":"☀","motto":"You matter"}"#]]
-            .assert_eq(&s);
-        let config = Config::default();
-        let s = serde_json::to_string(&config).unwrap();
-        expect![[r#"{"yes_or_no":false,"delta":0,"max":0,"delimeter":"\u0000","motto":""}"#]]
-            .assert_eq(&s);
-
-        #[cfg(feature = "inline")]
-        {
-            let config: InlineConfig = serde_json::from_str(INLINE_JSON).unwrap();
-            let s = serde_json::to_string(&config).unwrap();
-            expect![[r#"{"inline_motto":"I matter even more","slice":["I","matter","even","more"],"slice_u64":[9000,8000,10000,7000]}"#]]
-        .assert_eq(&s);
-            let config: InlineConfig = serde_json::from_str(EMPTY_JSON).unwrap();
-            let s = serde_json::to_string(&config).unwrap();
-            expect![[
-                r#"{"inline_motto":"You matter even more","slice":["You","matter","even","more"],"slice_u64":[98712,12346,129389,102937]}"#
-            ]].assert_eq(&s);
+/// // Reads an environment variable at build time, without a fallback the build fails when it's unset
+/// // Generates
+/// // pub const fn default_api_base() -> &'static ::core::primitive::str {
+/// //     match option_env!("API_BASE_URL") {
+/// //         Some(value) => value,
+/// //         None => "https://api.example.com",
+/// //     }
+/// // }
+/// serde_default!(api_base, env "API_BASE_URL", "https://api.example.com");
+/// serde_default!(package_name, env "CARGO_PKG_NAME");
+///
+/// // Picks the value by cfg, the first matching arm wins and `_` is the fallback
+/// // Generates
+/// // pub const fn default_ipc_endpoint() -> &'static ::core::primitive::str {
+/// //     #[cfg(target_os = "linux")]
+/// //     let value = "/run/app.sock";
+/// //     ...
+/// // }
+/// serde_default!(ipc_endpoint, cfg {
+///     target_os = "linux" => "/run/app.sock",
+///     windows => r"\\.\pipe\app",
+///     _ => "/tmp/app.sock",
+/// });
+/// // Unsuffixed integers come out as `i32`, `name: Type, cfg { ... }` sets the return type
+/// serde_default!(admin_port: u16, cfg { target_os = "linux" => 8080, _ => 80 });
+///
+/// // `some` wraps a string literal or a `Type = expr` default in `Some`, `String = "..."` needs `alloc`
+/// // Generates
+/// // pub const fn default_proxy() -> Option<&'static ::core::primitive::str> {
+/// //     Some("http://localhost:3128")
+/// // }
+/// serde_default!(some proxy, "http://localhost:3128");
+/// serde_default!(some workers: u16 = 4);
+///
+/// // `as` names the function as is, for fields that need a dedicated default
+/// // Generates
+/// // pub const fn max_retries() -> u32 {
+/// //     5
+/// // }
+/// serde_default!(as max_retries, u32 = 5);
+///
+/// // Several defaults at once, separated by commas
+/// serde_default! {
+///     welcome: "hello",
+///     separator: ",",
+///     max_depth: u8 = 8,
+/// }
+///
+/// // Groups defaults of one struct in a module, fields take anything that follows the name in other arms
+/// // Generates
+/// // pub mod server_defaults {
+/// //     pub const fn default_port() -> u16 {
+/// //         8080
+/// //     }
+/// //     pub const fn default_host() -> &'static ::core::primitive::str {
+/// //         "localhost"
+/// //     }
+/// // }
+/// serde_default!(mod server_defaults { port: u16 = 8080, host: "localhost" });
+///
+/// // Any arm takes a visibility in front of the name, without one the function is `pub`
+/// // Generates
+/// // pub(crate) const fn default_motto() -> &'static ::core::primitive::str {
+/// //     "You matter"
+/// // }
+/// serde_default!(pub(crate) motto, "You matter");
+///
+/// // Needs `alloc` feature (enabled by `std`)
+/// // Generates
+/// // pub fn default_min_version() -> String {
+/// //     String::from("1.0.0")
+/// // }
+/// serde_default!(min_version, String = "1.0.0");
+///
+/// // Needs `alloc` feature, same for `Arc<str>` and `Rc<str>`
+/// // Generates
+/// // pub fn default_region() -> Box<str> {
+/// //     Box::from("eu-west-1")
+/// // }
+/// serde_default!(region, Box<str> = "eu-west-1");
+/// serde_default!(shared_region, Arc<str> = "eu-west-1");
+/// serde_default!(local_region, Rc<str> = "eu-west-1");
+///
+/// // Needs `smol_str` or `compact_str` feature, nothing is allocated
+/// // Generates
+/// // pub const fn default_zone() -> smol_str::SmolStr {
+/// //     smol_str::SmolStr::new_static("eu-west-1a")
+/// // }
+/// # #[cfg(feature = "smol_str")]
+/// serde_default!(zone, SmolStr = "eu-west-1a");
+/// # #[cfg(feature = "compact_str")]
+/// serde_default!(rack, CompactString = "r42");
+///
+/// // Needs `secrecy` feature, the value never shows up in `Debug` output
+/// // Generates
+/// // pub fn default_api_key() -> secrecy::SecretString {
+/// //     secrecy::SecretString::from("change-me")
+/// // }
+/// # #[cfg(feature = "secrecy")]
+/// serde_default!(api_key, SecretString = "change-me");
+///
+/// // Needs `alloc` feature, the element type comes from the first suffixed literal
+/// // and string literals become `String`s
+/// // Generates
+/// // pub fn default_ports() -> Vec<u16> {
+/// //     vec![8080u16, 8081, 8082]
+/// // }
+/// serde_default!(ports, vec![8080u16, 8081, 8082]);
+/// serde_default!(upstreams, vec!["a.example.com", "b.example.com"]);
+///
+/// // Needs `std` feature, `btree_map` gives a `BTreeMap` and only needs `alloc`,
+/// // string literals become `String`s
+/// // Generates
+/// // pub fn default_limits() -> HashMap<String, u32> {
+/// //     HashMap::from_iter([("read".into(), 100u32), ("write".into(), 10)])
+/// // }
+/// serde_default!(limits, map { "read" => 100u32, "write" => 10 });
+/// serde_default!(sorted_limits, btree_map { "read" => 100u32, "write" => 10 });
+///
+/// // Needs `std` feature, `btree_set` gives a `BTreeSet` and only needs `alloc`
+/// // Generates
+/// // pub fn default_allowed_methods() -> HashSet<String> {
+/// //     HashSet::from_iter(["GET".into(), "POST".into()])
+/// // }
+/// serde_default!(allowed_methods, set ["GET", "POST"]);
+/// serde_default!(sorted_allowed, btree_set ["GET", "POST"]);
+///
+/// // Needs `indexmap` feature, keeps the insertion order
+/// // Generates
+/// // pub fn default_steps() -> indexmap::IndexMap<String, u32> {
+/// //     IndexMap::from_iter([("build".into(), 1u32), ("test".into(), 2)])
+/// // }
+/// # #[cfg(feature = "indexmap")]
+/// serde_default!(steps, index_map { "build" => 1u32, "test" => 2 });
+/// # #[cfg(feature = "indexmap")]
+/// serde_default!(stages, index_set ["build", "test"]);
+///
+/// // Needs `smallvec` feature, spills to the heap past the inline capacity
+/// // Generates
+/// // pub fn default_peers() -> smallvec::SmallVec<[u16; 4]> {
+/// //     SmallVec::from_iter([7001u16, 7002])
+/// // }
+/// # #[cfg(feature = "smallvec")]
+/// serde_default!(peers, smallvec<4> [7001u16, 7002]);
+///
+/// // Needs `arrayvec` feature, fails to compile when the literals don't fit
+/// // Generates
+/// // pub fn default_retries() -> arrayvec::ArrayVec<u64, 8> {
+/// //     ArrayVec::from_iter([100u64, 200, 400])
+/// // }
+/// # #[cfg(feature = "arrayvec")]
+/// serde_default!(retries, arrayvec<8> [100u64, 200, 400]);
+///
+/// // Needs `nonempty` feature, an empty list doesn't match the arm,
+/// // string literals become `String`s
+/// // Generates
+/// // pub fn default_seeds() -> nonempty::NonEmpty<String> {
+/// //     NonEmpty { head: "seed-1:7000".into(), tail: vec!["seed-2:7000".into()] }
+/// // }
+/// # #[cfg(feature = "nonempty")]
+/// serde_default!(seeds, nonempty ["seed-1:7000", "seed-2:7000"]);
+///
+/// // Needs `bytes` feature, nothing is allocated
+/// // Generates
+/// // pub const fn default_greeting() -> bytes::Bytes {
+/// //     Bytes::from_static(b"hello")
+/// // }
+/// # #[cfg(feature = "bytes")]
+/// serde_default!(greeting, bytes b"hello");
+/// # #[cfg(feature = "bytes")]
+/// serde_default!(preamble, bytes [0xca, 0xfe]);
+///
+/// // Needs `alloc` feature, `&base64` gives a `&'static [u8]` and works without it,
+/// // fails to compile on invalid base64
+/// // Generates
+/// // pub fn default_salt() -> Vec<u8> {
+/// //     vec![104, 101, 108, 108, 111]
+/// // }
+/// serde_default!(salt, base64 "aGVsbG8=");
+/// serde_default!(key, &base64 "aGVsbG8=");
+///
+/// // Same for hex, `&hex` gives a `&'static [u8]`
+/// // Generates
+/// // pub const fn default_magic() -> &'static [u8] {
+/// //     &[0xde, 0xad, 0xbe, 0xef]
+/// // }
+/// serde_default!(magic, &hex "deadbeef");
+/// serde_default!(node_id, hex "00ff00ff");
+///
+/// // Needs `json` feature
+/// // Generates
+/// // pub fn default_extra() -> serde_json::Value {
+/// //     serde_json::json!({"retries": 3, "tags": []})
+/// // }
+/// # #[cfg(feature = "json")]
+/// serde_default!(extra, json!({"retries": 3, "tags": []}));
+///
+/// // Needs `yaml` feature, the document is validated at compile time
+/// // Generates
+/// // pub fn default_labels() -> serde_yaml::Value {
+/// //     serde_yaml::from_str("app: web\ntier: frontend").unwrap()
+/// // }
+/// # #[cfg(feature = "yaml")]
+/// serde_default!(labels, yaml "app: web\ntier: frontend");
+///
+/// // Each element is typed like a one-element list, so suffix integers other than `i32`
+/// // Generates
+/// // pub const fn default_window() -> (u32, u32) {
+/// //     (800u32, 600u32)
+/// // }
+/// serde_default!(window, (800u32, 600u32));
+/// serde_default!(origin, (0.5, "center"));
+///
+/// // Needs `either` feature, string literals become `String`s
+/// // Generates
+/// // pub fn default_limit() -> either::Either<u32, String> {
+/// //     either::Either::Left(10)
+/// // }
+/// # #[cfg(feature = "either")]
+/// serde_default!(limit, Either<u32, String> = Left(10));
+/// # #[cfg(feature = "either")]
+/// serde_default!(fallback, Either<u32, String> = Right("unlimited"));
+///
+/// // Needs `heapless` feature, fails to compile when the literal doesn't fit
+/// // Generates
+/// // pub fn default_boot() -> heapless::String<16> {
+/// //     heapless::String::try_from("boot").unwrap()
+/// // }
+/// # #[cfg(feature = "heapless")]
+/// serde_default!(boot, heapless::String<16> = "boot");
+/// # #[cfg(feature = "heapless")]
+/// serde_default!(pins, heapless::Vec<u8, 4> = [2, 3]);
+///
+/// // Needs `bitflags` feature, the flags are combined with the `const` `union`
+/// // Generates
+/// // pub const fn default_perms() -> Perms {
+/// //     Perms::READ.union(Perms::WRITE)
+/// // }
+/// # #[cfg(feature = "bitflags")]
+/// # bitflags::bitflags! {
+/// #     pub struct Perms: u8 {
+/// #         const READ = 1;
+/// #         const WRITE = 2;
+/// #     }
+/// # }
+/// # #[cfg(feature = "bitflags")]
+/// serde_default!(perms, Perms::READ | Perms::WRITE);
+///
+/// // Needs `regex` feature, the pattern is validated at compile time
+/// // and compiled once, every call clones the shared `Regex`
+/// // Generates
+/// // pub fn default_pattern() -> regex::Regex {
+/// //     static REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\w+$").unwrap());
+/// //     REGEX.clone()
+/// // }
+/// # #[cfg(feature = "regex")]
+/// serde_default!(pattern, regex r"^\w+$");
+///
+/// // Needs `cron` feature, the expression is validated at compile time
+/// // Generates
+/// // pub fn default_schedule() -> cron::Schedule {
+/// //     "0 0 * * * *".parse().unwrap()
+/// // }
+/// # #[cfg(feature = "cron")]
+/// serde_default!(schedule, cron "0 0 * * * *");
+///
+/// // Needs `log` or `tracing` feature, fails to compile on an unknown level
+/// // Generates
+/// // pub const fn default_log_level() -> log::LevelFilter {
+/// //     log::LevelFilter::Info
+/// // }
+/// # #[cfg(feature = "log")]
+/// serde_default!(log_level, log_level "info");
+/// # #[cfg(feature = "tracing")]
+/// serde_default!(trace_level, tracing_level "debug");
+///
+/// // Needs `http` feature, status codes have to be in `100..=999`,
+/// // methods and header values are validated at compile time
+/// // Generates
+/// // pub fn default_status() -> http::StatusCode {
+/// //     http::StatusCode::from_u16(503).unwrap()
+/// // }
+/// # #[cfg(feature = "http")]
+/// serde_default!(status, status 503);
+/// # #[cfg(feature = "http")]
+/// serde_default!(method, method "POST");
+/// # #[cfg(feature = "http")]
+/// serde_default!(user_agent, header_value "my-proxy/1.0");
+///
+/// // Needs `mime` feature, the media type is validated at compile time
+/// // Generates
+/// // pub fn default_content_type() -> mime::Mime {
+/// //     "application/json".parse().unwrap()
+/// // }
+/// # #[cfg(feature = "mime")]
+/// serde_default!(content_type, mime "application/json");
+///
+/// // Fails to compile on an unknown unit or overflow,
+/// // `KB`/`MB`/`GB` are powers of 1000 and `KiB`/`MiB`/`GiB` powers of 1024
+/// // Generates
+/// // pub const fn default_max_body() -> u64 {
+/// //     10485760
+/// // }
+/// serde_default!(max_body, bytesize "10MiB");
+///
+/// // Fails to compile outside of `0..=100%`, `percent100` keeps the `0..=100` scale
+/// // Generates
+/// // pub const fn default_sample_rate() -> f64 {
+/// //     0.025
+/// // }
+/// serde_default!(sample_rate, percent "2.5%");
+/// serde_default!(alert_threshold, percent100 "80%");
+///
+/// // Needs `money` feature, fails to compile on an invalid amount or currency code
+/// // Generates
+/// // pub const fn default_price() -> Money {
+/// //     /* 19.99 USD */
+/// // }
+/// # #[cfg(feature = "money")]
+/// serde_default!(price, money "19.99 USD");
+///
+/// // Needs `langid` feature, the identifier is validated at compile time
+/// // Generates
+/// // pub fn default_ui_locale() -> unic_langid::LanguageIdentifier {
+/// //     "en-US".parse().unwrap()
+/// // }
+/// # #[cfg(feature = "langid")]
+/// serde_default!(ui_locale, lang "en-US");
+///
+/// // Needs `glam` feature, works for any `glam` vector with a `new` constructor
+/// // Generates
+/// // pub const fn default_gravity() -> glam::Vec3 {
+/// //     glam::Vec3::new(0.0, -9.81, 0.0)
+/// // }
+/// # #[cfg(feature = "glam")]
+/// serde_default!(gravity, glam::Vec3 = [0.0, -9.81, 0.0]);
+///
+/// // Needs `mint` feature
+/// // Generates
+/// // pub fn default_spawn() -> mint::Point2<i32> {
+/// //     mint::Point2::from([10, 20])
+/// // }
+/// # #[cfg(feature = "mint")]
+/// serde_default!(spawn, mint::Point2<i32> = [10, 20]);
+///
+/// // Needs `uom` feature, any `f64` SI quantity with one of its units
+/// // Generates
+/// // pub fn default_max_height() -> uom::si::f64::Length {
+/// //     uom::si::f64::Length::new::<uom::si::length::meter>(2.5)
+/// // }
+/// # #[cfg(feature = "uom")]
+/// serde_default!(max_height, uom Length = 2.5 meter);
+/// # #[cfg(feature = "uom")]
+/// serde_default!(timeout, uom Time = 30.0 second);
+///
+/// // Fails to compile unless the mode is written as an octal literal up to `0o7777`,
+/// // `permissions` gives `std::fs::Permissions` and needs `std` on unix
+/// // Generates
+/// // pub const fn default_mode() -> u32 {
+/// //     0o644
+/// // }
+/// serde_default!(mode, octal 0o644);
+/// # #[cfg(unix)]
+/// serde_default!(dir_permissions, permissions 0o755);
+///
+/// // !Experimental!
+/// // (not sure if it's even useful, but this allows you to store raw bytes)
+/// // Generates
+/// // pub const fn default_arr() -> &'static [::core::primitive::u8] {
+/// //     &[1,2,3,4,5]
+/// // }
+/// serde_default!(arr, &[1,2,3,4,5]);
+///
+/// // Other literals work too, suffix the first number to pick its type
+/// // Generates
+/// // pub const fn default_names() -> &'static [&'static ::core::primitive::str] {
+/// //     &["a", "b"]
+/// // }
+/// serde_default!(names, &["a", "b"]);
+/// serde_default!(weights, &[-1i32, 2, 3]);
+///
+/// // Needs `std` feature, `&path` gives a `&'static Path` instead
+/// // Generates
+/// // pub fn default_log_dir() -> ::std::path::PathBuf {
+/// //     ::std::path::PathBuf::from("/var/log/app")
+/// // }
+/// serde_default!(log_dir, path "/var/log/app");
+/// serde_default!(socket_path, &path "/run/app.sock");
+///
+/// // Needs `std` feature, `&os_string` gives a `&'static OsStr` instead
+/// // Generates
+/// // pub fn default_shell() -> ::std::ffi::OsString {
+/// //     ::std::ffi::OsString::from("/bin/sh")
+/// // }
+/// serde_default!(shell, os_string "/bin/sh");
+/// serde_default!(editor, &os_string "vi");
+///
+/// // Needs `alloc` feature, fails to compile on an interior NUL,
+/// // `&cstring` gives a `&'static CStr` and works without `alloc`
+/// // Generates
+/// // pub fn default_device() -> ::alloc::ffi::CString {
+/// //     ::alloc::ffi::CString::from(c"/dev/ttyUSB0")
+/// // }
+/// serde_default!(device, cstring "/dev/ttyUSB0");
+/// serde_default!(locale, &cstring "C");
+///
+/// // Needs `dirs` feature, per-user directories with an app name appended
+/// // Generates
+/// // pub fn default_app_config() -> Option<::std::path::PathBuf> {
+/// //     default_config_dir().map(|dir| dir.join("my-app"))
+/// // }
+/// # #[cfg(feature = "dirs")]
+/// serde_default!(app_config, config_dir "my-app");
+/// # #[cfg(feature = "dirs")]
+/// serde_default!(app_cache, cache_dir "my-app");
+/// # #[cfg(feature = "dirs")]
+/// serde_default!(app_data, data_dir "my-app");
+///
+/// // Fails to compile on an invalid address, `ipv4` and `ipv6` give the specific types
+/// // Generates
+/// // pub const fn default_bind_ip() -> ::core::net::IpAddr {
+/// //     /* 0.0.0.0 */
+/// // }
+/// serde_default!(bind_ip, ip "0.0.0.0");
+/// serde_default!(loopback, ipv6 "::1");
+/// // pub const fn default_listen() -> ::core::net::SocketAddr {
+/// //     /* 0.0.0.0:8080 */
+/// // }
+/// serde_default!(listen, socket "0.0.0.0:8080");
+///
+/// // Needs `ipnet` feature
+/// // Generates
+/// // pub const fn default_allowed() -> ipnet::IpNet {
+/// //     /* 10.0.0.0/8 */
+/// // }
+/// # #[cfg(feature = "ipnet")]
+/// serde_default!(allowed, cidr "10.0.0.0/8");
+///
+/// // Needs `url` feature, fails to compile on an invalid url
+/// // Generates
+/// // pub fn default_endpoint() -> url::Url {
+/// //     /* https://api.example.com/v1 */
+/// // }
+/// # #[cfg(feature = "url")]
+/// serde_default!(endpoint, url "https://api.example.com/v1");
+///
+/// // Needs `email` feature, fails to compile on an invalid address
+/// // Generates
+/// // pub fn default_admin_email() -> email_address::EmailAddress {
+/// //     /* admin@example.com */
+/// // }
+/// # #[cfg(feature = "email")]
+/// serde_default!(admin_email, email "admin@example.com");
+///
+/// // Needs `decimal` feature, fails to compile on an invalid or out of range number
+/// // Generates
+/// // pub const fn default_fee() -> rust_decimal::Decimal {
+/// //     /* 0.015 */
+/// // }
+/// # #[cfg(feature = "decimal")]
+/// serde_default!(fee, decimal "0.015");
+///
+/// // Needs `bigdecimal` feature, the number is validated at compile time
+/// // Generates
+/// // pub fn default_balance() -> bigdecimal::BigDecimal {
+/// //     /* 123456789012345678901234567890.000001 */
+/// // }
+/// # #[cfg(feature = "bigdecimal")]
+/// serde_default!(balance, bigdecimal "123456789012345678901234567890.000001");
+///
+/// // Needs `num-bigint` feature, the number is validated at compile time
+/// // Generates
+/// // pub fn default_modulus() -> num_bigint::BigUint {
+/// //     /* 340282366920938463463374607431768211507 */
+/// // }
+/// # #[cfg(feature = "num-bigint")]
+/// serde_default!(modulus, biguint "340282366920938463463374607431768211507");
+/// # #[cfg(feature = "num-bigint")]
+/// serde_default!(offset, bigint "-340282366920938463463374607431768211507");
+///
+/// // Needs `num-rational` feature, fails to compile on a zero denominator or overflow
+/// // Generates
+/// // pub const fn default_ratio() -> num_rational::Ratio<i64> {
+/// //     /* 3/4 */
+/// // }
+/// # #[cfg(feature = "num-rational")]
+/// serde_default!(ratio, ratio "6/8");
+///
+/// // Needs `ordered-float` feature, `not_nan` fails to compile on NaN
+/// // Generates
+/// // pub const fn default_weight() -> ordered_float::OrderedFloat<f64> {
+/// //     ordered_float::OrderedFloat(0.5)
+/// // }
+/// # #[cfg(feature = "ordered-float")]
+/// serde_default!(weight, ordered 0.5);
+/// # #[cfg(feature = "ordered-float")]
+/// serde_default!(threshold, not_nan 0.25);
+///
+/// // Needs `humantime` feature, the string is parsed at compile time
+/// // Generates
+/// // pub const fn default_poll_interval() -> ::core::time::Duration {
+/// //     ::core::time::Duration::new(330, 0)
+/// // }
+/// # #[cfg(feature = "humantime")]
+/// serde_default!(poll_interval, duration "5m30s");
+///
+/// // Needs `chrono` feature, both are validated at compile time
+/// // Generates
+/// // pub const fn default_start_date() -> chrono::NaiveDate {
+/// //     /* 2024-01-01 */
+/// // }
+/// # #[cfg(feature = "chrono")]
+/// serde_default!(start_date, date "2024-01-01");
+/// // pub const fn default_launch() -> chrono::DateTime<chrono::Utc> {
+/// //     /* 2024-01-01T00:00:00Z */
+/// // }
+/// # #[cfg(feature = "chrono")]
+/// serde_default!(launch, datetime "2024-01-01T00:00:00Z");
+///
+/// // Needs `time` feature, same as above but for the `time` crate
+/// // Generates
+/// // pub const fn default_release() -> time::Date { /* 2024-01-01 */ }
+/// # #[cfg(feature = "time")]
+/// serde_default!(release, time::date "2024-01-01");
+/// // pub const fn default_cutoff() -> time::Time { /* 23:59:59.5 */ }
+/// # #[cfg(feature = "time")]
+/// serde_default!(cutoff, time::time "23:59:59.5");
+/// // pub const fn default_epoch() -> time::OffsetDateTime { /* 2024-01-01T00:00:00+02:00 */ }
+/// # #[cfg(feature = "time")]
+/// serde_default!(epoch, time::datetime "2024-01-01T00:00:00+02:00");
+///
+/// // Needs `jiff` feature, `Timestamp` is const, `Zoned` and `Span` are validated
+/// // at compile time and parsed on call, so the time zone has to be available at runtime
+/// // Generates
+/// // pub const fn default_created() -> jiff::Timestamp { /* 2024-01-01T00:00:00Z */ }
+/// # #[cfg(feature = "jiff")]
+/// serde_default!(created, jiff::timestamp "2024-01-01T00:00:00Z");
+/// // pub fn default_meeting() -> jiff::Zoned { /* 2024-01-01T09:00:00+01:00[+01:00] */ }
+/// # #[cfg(feature = "jiff")]
+/// serde_default!(meeting, jiff::zoned "2024-01-01T09:00:00+01:00[+01:00]");
+/// // named zones like `[Europe/Berlin]` need `std` for the time zone database
+/// # #[cfg(all(feature = "jiff", feature = "std"))]
+/// serde_default!(standup, jiff::zoned "2024-07-01T09:00:00+02:00[Europe/Berlin]");
+/// // pub fn default_retention() -> jiff::Span { /* 30 days */ }
+/// # #[cfg(feature = "jiff")]
+/// serde_default!(retention, jiff::span "30 days");
+///
+/// // Needs `chrono-tz` feature, the name is validated at compile time
+/// // Generates
+/// // pub fn default_tz() -> chrono_tz::Tz { /* Europe/Berlin */ }
+/// # #[cfg(feature = "chrono-tz")]
+/// serde_default!(tz, timezone "Europe/Berlin");
+///
+/// // Needs `uuid` feature, fails to compile on an invalid uuid
+/// // Generates
+/// // pub const fn default_tenant() -> uuid::Uuid {
+/// //     /* 67e55044-10b1-426f-9247-bb680e5fe0c8 */
+/// // }
+/// # #[cfg(feature = "uuid")]
+/// serde_default!(tenant, uuid "67e55044-10b1-426f-9247-bb680e5fe0c8");
+///
+/// // Needs `ulid` feature, fails to compile on an invalid ulid
+/// // Generates
+/// // pub const fn default_trace() -> ulid::Ulid {
+/// //     /* 01ARZ3NDEKTSV4RRFFQ69G5FAV */
+/// // }
+/// # #[cfg(feature = "ulid")]
+/// serde_default!(trace, ulid "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+///
+/// assert!(default_u8::<6>() == 6u8);
+/// assert_eq!(default_some_u8::<6>(), Some(6u8));
+/// assert_eq!(default_array_u8::<6, 3>(), [6u8; 3]);
+/// assert_eq!(default_hey(), "hey");
+/// assert_eq!(default_min_version(), String::from("1.0.0"));
+/// assert_eq!(default_ports(), vec![8080, 8081, 8082]);
+/// assert_eq!(default_limits()["read"], 100);
+/// assert!(default_allowed_methods().contains("GET"));
+/// assert_eq!(default_log_dir(), std::path::PathBuf::from("/var/log/app"));
+/// assert_eq!(default_socket_path(), std::path::Path::new("/run/app.sock"));
+/// assert_eq!(default_bind_ip(), std::net::Ipv4Addr::UNSPECIFIED);
+/// assert_eq!(default_loopback(), std::net::Ipv6Addr::LOCALHOST);
+/// assert_eq!(default_listen().port(), 8080);
+/// # #[cfg(feature = "humantime")]
+/// assert_eq!(default_poll_interval(), std::time::Duration::from_secs(330));
+/// assert_eq!(default_arr(), &[1,2,3,4,5]);
+///
+/// ```
+///
+/// Names that generate the same function in one call are reported at both of them. Across separate
+/// calls this is left to rustc, each call expands on its own so none of them sees the others, and its
+/// "the name `default_motto` is defined multiple times" already points at both calls
+/// ```compile_fail
+/// use serde_default_utils::*;
+///
+/// serde_default! {
+///     motto: "You matter",
+///     Motto: "You matter more",
+/// }
+/// ```
+#[macro_export]
+macro_rules! serde_default {
+    (@kind [$vis:vis] $kind:ty) => {
+        $crate::__private::paste! {
+            #[doc = "Default value: the const parameter `V`"]
+            $vis const fn [<default_$kind:lower>]<const V: $kind>() -> $kind {
+                V
+            }
+            #[doc = "Default value: `Some` of the const parameter `V`"]
+            $vis const fn [<default_some_$kind:lower>]<const V: $kind>() -> ::core::option::Option<$kind> {
+                ::core::option::Option::Some(V)
+            }
+            #[doc = "Default value: `N` copies of the const parameter `V`"]
+            $vis const fn [<default_array_$kind:lower>]<const V: $kind, const N: usize>() -> [$kind; N] {
+                [const { V }; N]
+            }
+            #[doc = "Checks for the value of [`default_" $kind:lower "`], made for `skip_serializing_if`"]
+            $vis fn [<is_default_$kind:lower>]<const V: $kind>(value: &$kind) -> bool {
+                *value == V
+            }
+            #[doc = "Checks for the value of [`default_some_" $kind:lower "`], made for `skip_serializing_if`"]
+            $vis fn [<is_default_some_$kind:lower>]<const V: $kind>(value: &::core::option::Option<$kind>) -> bool {
+                *value == ::core::option::Option::Some(V)
+            }
+            #[doc = "Checks for the value of [`default_array_" $kind:lower "`], made for `skip_serializing_if`"]
+            $vis fn [<is_default_array_$kind:lower>]<const V: $kind, const N: usize>(value: &[$kind; N]) -> bool {
+                value.iter().all(|item| *item == V)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] mod $module:ident { $($fields:tt)* }) => {
+        $(#[$attr])*
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::__private::mod_fields!($crate; [] $($fields)*);
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident,$text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> &'static ::core::primitive::str {
+                $text
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, String = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::String {
+                $crate::__private::String::from($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, Box<str> = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::Box<str> {
+                $crate::__private::Box::from($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, Arc<str> = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::Arc<str> {
+                $crate::__private::Arc::from($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, SmolStr = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::smol_str::SmolStr {
+                $crate::__private::smol_str::SmolStr::new_static($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, CompactString = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::compact_str::CompactString {
+                $crate::__private::compact_str::CompactString::const_new($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, SecretString = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::secrecy::SecretString {
+                $crate::__private::secrecy::SecretString::from($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, Rc<str> = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::Rc<str> {
+                $crate::__private::Rc::from($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, path $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::PathBuf {
+                $crate::__private::PathBuf::from($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, &path $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> &'static $crate::__private::Path {
+                $crate::__private::Path::new($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, cstring $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::CString {
+                $crate::__private::CString::from($crate::serde_default!(@cstr $text))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, &cstring $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> &'static ::core::ffi::CStr {
+                $crate::serde_default!(@cstr $text)
+            }
+        }
+    };
+    (@cstr $text:literal) => {
+        const {
+            match ::core::ffi::CStr::from_bytes_with_nul(concat!($text, "\0").as_bytes()) {
+                ::core::result::Result::Ok(cstr) => cstr,
+                ::core::result::Result::Err(_) => ::core::panic!(concat!("interior NUL in: ", $text)),
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, os_string $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::OsString {
+                $crate::__private::OsString::from($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, &os_string $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> &'static $crate::__private::OsStr {
+                $crate::__private::OsStr::new($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, config_dir $app:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> ::core::option::Option<$crate::__private::PathBuf> {
+                $crate::default_config_dir().map(|dir| dir.join($app))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, cache_dir $app:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> ::core::option::Option<$crate::__private::PathBuf> {
+                $crate::default_cache_dir().map(|dir| dir.join($app))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, data_dir $app:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> ::core::option::Option<$crate::__private::PathBuf> {
+                $crate::default_data_dir().map(|dir| dir.join($app))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, ip $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::net::IpAddr {
+                const {
+                    match $crate::__private::parse_ip($text) {
+                        ::core::option::Option::Some(ip) => ip,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid ip address: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, ipv4 $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::net::Ipv4Addr {
+                const {
+                    match $crate::__private::parse_ipv4($text) {
+                        ::core::option::Option::Some(ip) => ip,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid ipv4 address: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, ipv6 $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::net::Ipv6Addr {
+                const {
+                    match $crate::__private::parse_ipv6($text) {
+                        ::core::option::Option::Some(ip) => ip,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid ipv6 address: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, socket $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::net::SocketAddr {
+                const {
+                    match $crate::__private::parse_socket_addr($text) {
+                        ::core::option::Option::Some(addr) => addr,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid socket address: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, cidr $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::ipnet::IpNet {
+                const {
+                    match $crate::__private::parse_ipnet($text) {
+                        ::core::option::Option::Some(net) => net,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid cidr: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, url $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::url::Url {
+                $crate::__private::url!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, email $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::email_address::EmailAddress {
+                $crate::__private::email!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, decimal $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::rust_decimal::Decimal {
+                const {
+                    match $crate::__private::parse_rust_decimal($text) {
+                        ::core::option::Option::Some(decimal) => decimal,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid decimal: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, bigdecimal $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::bigdecimal::BigDecimal {
+                $crate::__private::bigdecimal!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, bigint $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::num_bigint::BigInt {
+                $crate::__private::bigint!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, biguint $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::num_bigint::BigUint {
+                $crate::__private::biguint!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, ratio $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::num_rational::Ratio<i64> {
+                const {
+                    match $crate::__private::parse_ratio($text) {
+                        ::core::option::Option::Some(ratio) => ratio,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid ratio: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, ordered $value:expr) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::ordered_float::OrderedFloat<f64> {
+                $crate::__private::ordered_float::OrderedFloat($value)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, not_nan $value:expr) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::ordered_float::NotNan<f64> {
+                const {
+                    let value: f64 = $value;
+                    if value.is_nan() {
+                        ::core::panic!(concat!("NaN is not allowed: ", stringify!($value)));
+                    }
+                    // SAFETY: checked above
+                    unsafe { $crate::__private::ordered_float::NotNan::new_unchecked(value) }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, duration $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::time::Duration {
+                $crate::__private::duration!($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, date $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::chrono::NaiveDate {
+                $crate::__private::chrono_date!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, datetime $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::chrono::DateTime<$crate::__private::chrono::Utc> {
+                $crate::__private::chrono_datetime!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, time::date $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::time::Date {
+                $crate::__private::time_date!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, time::time $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::time::Time {
+                $crate::__private::time_time!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, time::datetime $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::time::OffsetDateTime {
+                $crate::__private::time_datetime!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, jiff::timestamp $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::jiff::Timestamp {
+                $crate::__private::jiff_timestamp!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, jiff::zoned $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::jiff::Zoned {
+                $crate::__private::jiff_zoned!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, jiff::span $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::jiff::Span {
+                $crate::__private::jiff_span!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, timezone $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::chrono_tz::Tz {
+                $crate::__private::timezone!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, uuid $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::uuid::Uuid {
+                const {
+                    match $crate::__private::uuid::Uuid::try_parse($text) {
+                        ::core::result::Result::Ok(uuid) => uuid,
+                        ::core::result::Result::Err(_) => ::core::panic!(concat!("invalid uuid: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, ulid $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::ulid::Ulid {
+                const {
+                    match $crate::__private::ulid::Ulid::from_string($text) {
+                        ::core::result::Result::Ok(ulid) => ulid,
+                        ::core::result::Result::Err(_) => ::core::panic!(concat!("invalid ulid: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, vec![ $($value:expr),* $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::Vec<
+                $crate::__private::Owned<$crate::__private::lit_ty!(::core::primitive::i32; $($value),*)>,
+            > {
+                type Value = $crate::__private::lit_ty!(::core::primitive::i32; $($value),*);
+                $crate::__private::vec![$(<Value as $crate::__private::OwnedLit>::into_owned($value)),*]
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, map { $($key:expr => $value:expr),* $(,)? }) => {
+        $crate::serde_default!(@map [$(#[$attr])* $vis] [$($prefix)*] $name, $crate::__private::HashMap, $($key => $value),*);
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, btree_map { $($key:expr => $value:expr),* $(,)? }) => {
+        $crate::serde_default!(@map [$(#[$attr])* $vis] [$($prefix)*] $name, $crate::__private::BTreeMap, $($key => $value),*);
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, index_map { $($key:expr => $value:expr),* $(,)? }) => {
+        $crate::serde_default!(@map [$(#[$attr])* $vis] [$($prefix)*] $name, $crate::__private::indexmap::IndexMap, $($key => $value),*);
+    };
+    (@map [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, $map:path, $($key:expr => $value:expr),*) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $map<
+                $crate::__private::Owned<$crate::__private::lit_ty!(::core::primitive::i32; $($key),*)>,
+                $crate::__private::Owned<$crate::__private::lit_ty!(::core::primitive::i32; $($value),*)>,
+            > {
+                type Key = $crate::__private::lit_ty!(::core::primitive::i32; $($key),*);
+                type Value = $crate::__private::lit_ty!(::core::primitive::i32; $($value),*);
+                ::core::iter::FromIterator::from_iter([$((
+                    <Key as $crate::__private::OwnedLit>::into_owned($key),
+                    <Value as $crate::__private::OwnedLit>::into_owned($value),
+                )),*])
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, set [ $($value:expr),* $(,)? ]) => {
+        $crate::serde_default!(@set [$(#[$attr])* $vis] [$($prefix)*] $name, $crate::__private::HashSet, $($value),*);
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, btree_set [ $($value:expr),* $(,)? ]) => {
+        $crate::serde_default!(@set [$(#[$attr])* $vis] [$($prefix)*] $name, $crate::__private::BTreeSet, $($value),*);
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, index_set [ $($value:expr),* $(,)? ]) => {
+        $crate::serde_default!(@set [$(#[$attr])* $vis] [$($prefix)*] $name, $crate::__private::indexmap::IndexSet, $($value),*);
+    };
+    (@set [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, $set:path, $($value:expr),*) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $set<
+                $crate::__private::Owned<$crate::__private::lit_ty!(::core::primitive::i32; $($value),*)>,
+            > {
+                type Value = $crate::__private::lit_ty!(::core::primitive::i32; $($value),*);
+                ::core::iter::FromIterator::from_iter([$(
+                    <Value as $crate::__private::OwnedLit>::into_owned($value)
+                ),*])
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, smallvec<$cap:literal> [ $($value:expr),* $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::smallvec::SmallVec<
+                [$crate::__private::lit_ty!(::core::primitive::i32; $($value),*); $cap],
+            > {
+                ::core::iter::FromIterator::from_iter([$($value),*])
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, arrayvec<$cap:literal> [ $($value:expr),* $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::arrayvec::ArrayVec<
+                $crate::__private::lit_ty!(::core::primitive::i32; $($value),*),
+                $cap,
+            > {
+                const {
+                    ::core::assert!(
+                        [$(stringify!($value)),*].len() <= $cap,
+                        concat!("too many values for arrayvec<", stringify!($cap), ">"),
+                    );
+                }
+                ::core::iter::FromIterator::from_iter([$($value),*])
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, nonempty [ $head:expr $(, $tail:expr)* $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::nonempty::NonEmpty<
+                $crate::__private::Owned<$crate::__private::lit_ty!(::core::primitive::i32; $head $(, $tail)*)>,
+            > {
+                type Value = $crate::__private::lit_ty!(::core::primitive::i32; $head $(, $tail)*);
+                $crate::__private::nonempty::NonEmpty {
+                    head: <Value as $crate::__private::OwnedLit>::into_owned($head),
+                    tail: $crate::__private::vec![$(<Value as $crate::__private::OwnedLit>::into_owned($tail)),*],
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, bytes $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::bytes::Bytes {
+                $crate::__private::bytes::Bytes::from_static($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, bytes [ $($value:expr),* $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::bytes::Bytes {
+                $crate::__private::bytes::Bytes::from_static(&[$($value),*])
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, base64 $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::Vec<::core::primitive::u8> {
+                $crate::__private::Vec::from($crate::serde_default!(@decode base64_len, decode_base64, "base64", $text))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, &base64 $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> &'static [::core::primitive::u8] {
+                $crate::serde_default!(@decode base64_len, decode_base64, "base64", $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, hex $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::Vec<::core::primitive::u8> {
+                $crate::__private::Vec::from($crate::serde_default!(@decode hex_len, decode_hex, "hex", $text))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, &hex $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> &'static [::core::primitive::u8] {
+                $crate::serde_default!(@decode hex_len, decode_hex, "hex", $text)
+            }
+        }
+    };
+    (@decode $len:ident, $decode:ident, $what:literal, $text:literal) => {{
+        const BYTES: [::core::primitive::u8; $crate::__private::$len($text)] = match $crate::__private::$decode($text) {
+            ::core::option::Option::Some(bytes) => bytes,
+            ::core::option::Option::None => ::core::panic!(concat!("invalid ", $what, ": ", $text)),
+        };
+        &BYTES
+    }};
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, json!($($json:tt)+)) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::serde_json::Value {
+                $crate::__private::serde_json::json!($($json)+)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, yaml $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::serde_yaml::Value {
+                $crate::__private::yaml!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, ( $($value:expr),+ $(,)? )) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ($($crate::__private::lit_ty!(::core::primitive::i32; $value),)+) {
+                ($($value,)+)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, Either<$left:ty, $right:ty> = $side:ident($value:expr)) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::either::Either<$left, $right> {
+                $crate::__private::either::Either::$side($crate::__private::LitInto::lit_into($value))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, heapless::String<$cap:literal> = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::heapless::String<$cap> {
+                const {
+                    ::core::assert!(
+                        $text.len() <= $cap,
+                        concat!("too long for heapless::String<", stringify!($cap), ">: ", $text),
+                    );
+                }
+                match $crate::__private::heapless::String::try_from($text) {
+                    ::core::result::Result::Ok(string) => string,
+                    ::core::result::Result::Err(_) => ::core::unreachable!("capacity checked at compile time"),
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, heapless::Vec<$kind:ty, $cap:literal> = [ $($value:expr),* $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::heapless::Vec<$kind, $cap> {
+                const VALUES: &[$kind] = &[$($value),*];
+                const {
+                    ::core::assert!(
+                        VALUES.len() <= $cap,
+                        concat!("too many values for heapless::Vec<_, ", stringify!($cap), ">"),
+                    );
+                }
+                match $crate::__private::heapless::Vec::from_slice(VALUES) {
+                    ::core::result::Result::Ok(vec) => vec,
+                    ::core::result::Result::Err(_) => ::core::unreachable!("capacity checked at compile time"),
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, $flags:ident::$flag:ident $(| $more_flags:ident::$more:ident)*) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $flags {
+                const _: fn() -> $flags = <$flags as $crate::__private::bitflags::Flags>::empty;
+                $flags::$flag$(.union($more_flags::$more))*
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, regex $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::regex::Regex {
+                static REGEX: $crate::__private::LazyLock<$crate::__private::regex::Regex> =
+                    $crate::__private::LazyLock::new(|| $crate::__private::regex!($crate, $text));
+                ::core::clone::Clone::clone(&*REGEX)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, cron $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::cron::Schedule {
+                $crate::__private::cron!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, log_level $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::log::LevelFilter {
+                const {
+                    match $crate::__private::parse_level_filter($text) {
+                        ::core::option::Option::Some(level) => level,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid log level: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, tracing_level $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::tracing::Level {
+                const {
+                    match $crate::__private::parse_tracing_level($text) {
+                        ::core::option::Option::Some(level) => level,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid tracing level: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, status $code:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::http::StatusCode {
+                const {
+                    ::core::assert!(
+                        100 <= $code && $code <= 999,
+                        concat!("invalid status code: ", stringify!($code)),
+                    );
+                }
+                $crate::__private::http::StatusCode::from_u16($code).expect("status code validated at compile time")
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, method $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::http::Method {
+                $crate::__private::http_method!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, header_value $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::http::HeaderValue {
+                const { $crate::__private::http::HeaderValue::from_static($text) }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, mime $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::mime::Mime {
+                $crate::__private::mime!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, bytesize $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::primitive::u64 {
+                const {
+                    match $crate::__private::parse_byte_size($text) {
+                        ::core::option::Option::Some(size) => size,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid byte size: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, percent $text:literal) => {
+        $crate::serde_default!(@percent [$(#[$attr])* $vis] [$($prefix)*] $name, false, $text);
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, percent100 $text:literal) => {
+        $crate::serde_default!(@percent [$(#[$attr])* $vis] [$($prefix)*] $name, true, $text);
+    };
+    (@percent [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, $points:literal, $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::primitive::f64 {
+                const {
+                    match $crate::__private::parse_percent($text, $points) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid percentage: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, money $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::Money {
+                const {
+                    match $crate::Money::parse($text) {
+                        ::core::option::Option::Some(money) => money,
+                        ::core::option::Option::None => ::core::panic!(concat!("invalid money: ", $text)),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, lang $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::unic_langid::LanguageIdentifier {
+                $crate::__private::langid!($crate, $text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, glam::$vector:ident = [ $($value:expr),+ $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::glam::$vector {
+                $crate::__private::glam::$vector::new($($value),+)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, mint::$vector:ident<$kind:ty> = [ $($value:expr),+ $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::mint::$vector<$kind> {
+                $crate::__private::mint::$vector::from([$($value),+])
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, uom $quantity:ident = $value:literal $unit:ident) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::uom::si::f64::$quantity {
+                $crate::__private::uom::si::f64::$quantity::new::<$crate::__private::uom::si::[<$quantity:snake>]::$unit>($value)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, octal $value:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::primitive::u32 {
+                $crate::serde_default!(@octal $value)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, permissions $value:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> $crate::__private::Permissions {
+                <$crate::__private::Permissions as $crate::__private::PermissionsExt>::from_mode(
+                    $crate::serde_default!(@octal $value),
+                )
+            }
+        }
+    };
+    (@octal $value:literal) => {
+        const {
+            let text = stringify!($value).as_bytes();
+            ::core::assert!(
+                text.len() > 2 && text[0] == b'0' && text[1] == b'o' && $value <= 0o7777,
+                concat!("expected an octal mode like 0o644, got ", stringify!($value)),
+            );
+            $value
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, &[ $($value:expr),* $(,)? ]) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> &'static [$crate::__private::lit_ty!(::core::primitive::u8; $($value),*)] {
+                &[$($value,)*]
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, $kind:ty = $value:expr) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $kind {
+                $value
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, parse $kind:ident $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $kind {
+                const {
+                    match $crate::__private::parse_integer($text) {
+                        ::core::option::Option::Some((true, magnitude))
+                            if magnitude <= ($kind::MIN as i128).unsigned_abs() =>
+                        {
+                            (magnitude as i128).wrapping_neg() as $kind
+                        }
+                        ::core::option::Option::Some((false, magnitude)) if magnitude <= $kind::MAX as u128 => {
+                            magnitude as $kind
+                        }
+                        _ => ::core::panic!(::core::concat!(
+                            "invalid ", ::core::stringify!($kind), ": ", $text
+                        )),
+                    }
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, env $var:literal, $fallback:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> &'static ::core::primitive::str {
+                match ::core::option_env!($var) {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => $fallback,
+                }
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, env $var:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> &'static ::core::primitive::str {
+                ::core::env!($var)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, cfg { $($arms:tt)+ }) => {
+        $crate::serde_default!(@cfg [$(#[$attr])* $vis] [$($prefix)*] $name [] value [] [] [] $($arms)+);
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident, cfg $kind:ty { $($arms:tt)+ }) => {
+        $crate::serde_default!(@cfg [$(#[$attr])* $vis] [$($prefix)*] $name [$kind] value [] [] [] $($arms)+);
+    };
+    (@cfg $head:tt $prefix:tt $name:ident $ret:tt $binding:ident [$($prev:meta),*] [$($lets:tt)*] [$($values:expr),*] _ => $value:expr $(,)?) => {
+        $crate::serde_default!(
+            @cfg_fn $head $prefix $name $ret $binding [$($values,)* $value]
+            $($lets)*
+            #[cfg(not(any($($prev),*)))]
+            let $binding = $value;
+        );
+    };
+    (@cfg $head:tt $prefix:tt $name:ident $ret:tt $binding:ident [$($prev:meta),*] [$($lets:tt)*] [$($values:expr),*] $cond:meta => $value:expr $(, $($rest:tt)*)?) => {
+        $crate::serde_default!(
+            @cfg $head $prefix $name $ret $binding [$($prev,)* $cond]
+            [$($lets)* #[cfg(all($cond, not(any($($prev),*))))] let $binding = $value;]
+            [$($values,)* $value]
+            $($($rest)*)?
+        );
+    };
+    (@cfg $head:tt $prefix:tt $name:ident $ret:tt $binding:ident [$($prev:meta),*] [$($lets:tt)*] [$($values:expr),*]) => {
+        $crate::serde_default!(
+            @cfg_fn $head $prefix $name $ret $binding [$($values),*]
+            $($lets)*
+            #[cfg(not(any($($prev),*)))]
+            let $binding = ::core::compile_error!(::core::concat!(
+                "no cfg matches the default of `", ::core::stringify!($name), "`, add a `_ => ...` fallback"
+            ));
+        );
+    };
+    (@cfg_fn [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident [] $binding:ident [$($values:expr),*] $($lets:tt)*) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $crate::__private::lit_ty!(::core::primitive::i32; $($values),*) {
+                $($lets)*
+                $binding
+            }
+        }
+    };
+    (@cfg_fn [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $name:ident [$kind:ty] $binding:ident [$($values:expr),*] $($lets:tt)*) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> $kind {
+                $($lets)*
+                $binding
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] some $name:ident, $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::option::Option<&'static ::core::primitive::str> {
+                ::core::option::Option::Some($text)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] some $name:ident, String = $text:literal) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis fn [<$($prefix)* $name:lower>]() -> ::core::option::Option<$crate::__private::String> {
+                ::core::option::Option::Some($crate::__private::String::from($text))
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] some $name:ident, $kind:ty = $value:expr) => {
+        $crate::__private::paste! {
+            $(#[$attr])* $vis const fn [<$($prefix)* $name:lower>]() -> ::core::option::Option<$kind> {
+                ::core::option::Option::Some($value)
+            }
+        }
+    };
+    (@vis [$(#[$attr:meta])* $vis:vis] [$($prefix:tt)*] $($kind:ty),+ $(,)?) => {
+        $($crate::serde_default!(@kind [$vis] $kind);)+
+    };
+    (@doc [$($vis:tt)*] mod $($rest:tt)+) => {
+        $crate::serde_default!(@vis [$($vis)*] [] mod $($rest)+);
+    };
+    (@doc [$($vis:tt)*] some $name:ident : $($rest:tt)+) => {
+        $crate::serde_default!(@doc [$($vis)*] some $name, $($rest)+);
+    };
+    (@doc [$($vis:tt)*] some $name:ident, $($rest:tt)+) => {
+        $crate::serde_default!(
+            @vis [#[doc = ::core::concat!("Default value: `Some(", $crate::__private::doc_value!($($rest)+), ")`")] $($vis)*] [default_]
+            some $name, $($rest)+
+        );
+    };
+    (@doc [$($vis:tt)*] as $name:ident : $($rest:tt)+) => {
+        $crate::serde_default!(@doc [$($vis)*] as $name, $($rest)+);
+    };
+    (@doc [$($vis:tt)*] as $name:ident, $($rest:tt)+) => {
+        $crate::serde_default!(
+            @vis [#[doc = ::core::concat!("Default value: `", $crate::__private::doc_value!($($rest)+), "`")] $($vis)*] []
+            $name, $($rest)+
+        );
+    };
+    (@doc [$($vis:tt)*] $name:ident : $($rest:tt)+) => {
+        $crate::__private::mod_fields!($crate; [$($vis)*] $name: $($rest)+);
+    };
+    (@doc [$($vis:tt)*] $name:ident, $($rest:tt)+) => {
+        $crate::serde_default!(
+            @vis [#[doc = ::core::concat!("Default value: `", $crate::__private::doc_value!($($rest)+), "`")] $($vis)*] [default_]
+            $name, $($rest)+
+        );
+    };
+    (@doc [$($vis:tt)*] $($rest:tt)+) => {
+        $crate::serde_default!(@vis [$($vis)*] [default_] $($rest)+);
+    };
+    (pub($($restriction:tt)+) $($rest:tt)+) => {
+        $crate::serde_default!(@doc [pub($($restriction)+)] $($rest)+);
+    };
+    (pub $($rest:tt)+) => {
+        $crate::serde_default!(@doc [pub] $($rest)+);
+    };
+    (mod $($rest:tt)+) => {
+        $crate::serde_default!(@doc [pub] mod $($rest)+);
+    };
+    (as $($rest:tt)+) => {
+        $crate::serde_default!(@doc [pub] as $($rest)+);
+    };
+    (some $($rest:tt)+) => {
+        $crate::serde_default!(@doc [pub] some $($rest)+);
+    };
+    ($name:ident, $($rest:tt)+) => {
+        $crate::serde_default!(@doc [pub] $name, $($rest)+);
+    };
+    ($name:ident : $($rest:tt)+) => {
+        $crate::serde_default!(@doc [pub] $name: $($rest)+);
+    };
+    ($($kind:ty),+ $(,)?) => {
+        $crate::serde_default!(@doc [pub] $($kind),+);
+    };
+}
+
+/// Generates a default function for an enum variant, named after the enum and the variant
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// enum LogLevel {
+///     Info,
+///     Debug,
+/// }
+///
+/// // Generates
+/// // pub const fn default_loglevel_info() -> LogLevel {
+/// //     LogLevel::Info
+/// // }
+/// serde_default_variant!(LogLevel::Info);
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(default = "default_loglevel_info")]
+///     level: LogLevel,
+/// }
+///
+/// let config: Config = serde_json::from_str("{}").unwrap();
+/// assert_eq!(config.level, LogLevel::Info);
+/// ```
+#[macro_export]
+macro_rules! serde_default_variant {
+    ($($ty:ident::$variant:ident),+ $(,)?) => {
+        $crate::__private::paste! {
+            $(
+                pub const fn [<default_$ty:lower _$variant:lower>]() -> $ty {
+                    $ty::$variant
+                }
+            )+
+        }
+    };
+}
+
+/// Generates a default function and a matching `skip_serializing_if` predicate from one value,
+/// the visibility is optional and `pub` without one
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serde_default_utils::*;
+///
+/// // Generates
+/// // pub const fn default_port() -> u16 {
+/// //     8080
+/// // }
+/// // pub fn is_default_port(value: &u16) -> bool {
+/// //     *value == default_port()
+/// // }
+/// serde_default_pair!(port, u16, 8080);
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(default = "default_port", skip_serializing_if = "is_default_port")]
+///     port: u16,
+/// }
+///
+/// let config: Config = serde_json::from_str("{}").unwrap();
+/// assert_eq!(config.port, 8080);
+/// assert_eq!(serde_json::to_string(&config).unwrap(), "{}");
+/// ```
+#[macro_export]
+macro_rules! serde_default_pair {
+    ($name:ident, $kind:ty, $value:expr $(,)?) => {
+        $crate::serde_default_pair!(pub $name, $kind, $value);
+    };
+    ($vis:vis $name:ident, $kind:ty, $value:expr $(,)?) => {
+        $crate::__private::paste! {
+            #[doc = ::core::concat!("Default value: `", ::core::stringify!($value), "`")]
+            $vis const fn [<default_$name:lower>]() -> $kind {
+                $value
+            }
+            #[doc = "Checks for the value of [`default_" $name:lower "`], made for `skip_serializing_if`"]
+            $vis fn [<is_default_$name:lower>](value: &$kind) -> bool {
+                *value == [<default_$name:lower>]()
+            }
+        }
+    };
+}
+
+/// Generates a default function for values that are expensive to build, the value is built once
+/// on first use and cloned from there, with `&Type` the function returns `&'static Type` instead.
+/// The visibility is optional and `pub` without one
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+/// use std::collections::BTreeMap;
+///
+/// // Generates
+/// // pub fn default_aliases() -> BTreeMap<String, String> {
+/// //     static VALUE: LazyLock<BTreeMap<String, String>> = LazyLock::new(|| /* ... */);
+/// //     VALUE.clone()
+/// // }
+/// serde_default_lazy!(aliases, BTreeMap<String, String> = (0..3)
+///     .map(|i| (format!("host{i}"), format!("10.0.0.{i}")))
+///     .collect());
+/// serde_default_lazy!(pub(crate) banner, &String = "=".repeat(80));
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(default = "default_aliases")]
+///     aliases: BTreeMap<String, String>,
+/// }
+///
+/// let config: Config = serde_json::from_str("{}").unwrap();
+/// assert_eq!(config.aliases["host1"], "10.0.0.1");
+/// assert_eq!(default_banner().len(), 80);
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! serde_default_lazy {
+    ($name:ident, $($rest:tt)+) => {
+        $crate::serde_default_lazy!(pub $name, $($rest)+);
+    };
+    ($vis:vis $name:ident, & $kind:ty = $value:expr $(,)?) => {
+        $crate::__private::paste! {
+            #[doc = ::core::concat!("Default value: `", ::core::stringify!($value), "`, built once")]
+            $vis fn [<default_$name:lower>]() -> &'static $kind {
+                static VALUE: $crate::__private::LazyLock<$kind> = $crate::__private::LazyLock::new(|| $value);
+                &VALUE
+            }
+        }
+    };
+    ($vis:vis $name:ident, $kind:ty = $value:expr $(,)?) => {
+        $crate::__private::paste! {
+            #[doc = ::core::concat!("Default value: `", ::core::stringify!($value), "`, built once")]
+            $vis fn [<default_$name:lower>]() -> $kind {
+                static VALUE: $crate::__private::LazyLock<$kind> = $crate::__private::LazyLock::new(|| $value);
+                ::core::clone::Clone::clone(&*VALUE)
+            }
+        }
+    };
+}
+
+/// Generates `default_build_git_sha()` and `default_build_timestamp()` from what
+/// [`build::emit`] passed from the build script, both are `"unknown"` without it
+///
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// // Generates
+/// // pub const fn default_build_git_sha() -> &'static str {
+/// //     /* commit hash */
+/// // }
+/// // pub const fn default_build_timestamp() -> &'static str {
+/// //     /* e.g. "2024-01-01T00:00:00Z" */
+/// // }
+/// serde_default_build!();
+///
+/// #[derive(Deserialize)]
+/// struct Status {
+///     #[serde(default = "default_build_git_sha")]
+///     git_sha: &'static str,
+///     #[serde(default = "default_build_timestamp")]
+///     built_at: &'static str,
+/// }
+/// ```
+#[cfg(feature = "build")]
+#[macro_export]
+macro_rules! serde_default_build {
+    () => {
+        $crate::serde_default_build!(pub);
+    };
+    ($vis:vis) => {
+        /// Git commit the crate was built from
+        $vis const fn default_build_git_sha() -> &'static ::core::primitive::str {
+            match ::core::option_env!("SERDE_DEFAULT_UTILS_GIT_SHA") {
+                ::core::option::Option::Some(sha) => sha,
+                ::core::option::Option::None => "unknown",
+            }
+        }
+
+        /// Time the crate was built at, in RFC 3339
+        $vis const fn default_build_timestamp() -> &'static ::core::primitive::str {
+            match ::core::option_env!("SERDE_DEFAULT_UTILS_BUILD_TIMESTAMP") {
+                ::core::option::Option::Some(timestamp) => timestamp,
+                ::core::option::Option::None => "unknown",
+            }
+        }
+    };
+}
+
+serde_default!(bool, char, usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
+macro_rules! serde_default_nonzero {
+    ($($kind:ident),* $(,)?) => {
+        $crate::__private::paste! {
+            $(
+                #[doc = "Same as [`default_" $kind "`], but fails to compile when `V` is zero"]
+                #[doc = "```compile_fail"]
+                #[doc = "let _ = serde_default_utils::default_nonzero_" $kind "::<0>();"]
+                #[doc = "```"]
+                pub const fn [<default_nonzero_ $kind>]<const V: $kind>() -> ::core::num::[<NonZero $kind:camel>] {
+                    const {
+                        match ::core::num::[<NonZero $kind:camel>]::new(V) {
+                            Some(value) => value,
+                            None => panic!("non-zero default can't be zero"),
+                        }
+                    }
+                }
+            )*
+        }
+    };
+}
+
+serde_default_nonzero!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
+macro_rules! serde_default_wrapping {
+    ($($kind:ident),* $(,)?) => {
+        $crate::__private::paste! {
+            $(
+                #[doc = "Same as [`default_" $kind "`], but wrapped in [`Wrapping`](core::num::Wrapping)"]
+                pub const fn [<default_wrapping_ $kind>]<const V: $kind>() -> ::core::num::Wrapping<$kind> {
+                    ::core::num::Wrapping(V)
+                }
+
+                #[doc = "Same as [`default_" $kind "`], but wrapped in [`Saturating`](core::num::Saturating)"]
+                pub const fn [<default_saturating_ $kind>]<const V: $kind>() -> ::core::num::Saturating<$kind> {
+                    ::core::num::Saturating(V)
+                }
+            )*
+        }
+    };
+}
+
+serde_default_wrapping!(usize, u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
+/// String default in the same turbofish style as integers, needs a nightly compiler
+/// ```rust
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(default = "default_str::<\"You matter\">")]
+///     motto: &'static str,
+/// }
+///
+/// let config: Config = serde_json::from_str("{}").unwrap();
+/// assert_eq!(config.motto, "You matter");
+/// ```
+#[cfg(feature = "nightly")]
+pub const fn default_str<const V: &'static str>() -> &'static str {
+    V
+}
+
+/// Derive for enums used as const generic defaults, `serde_default!(Level)` then generates
+/// `default_level::<{ Level::Debug }>()` and the rest of the integer helpers for it
+/// ```rust
+/// #![feature(adt_const_params)]
+/// use serde::Deserialize;
+/// use serde_default_utils::*;
+///
+/// #[derive(ConstParamTy, Deserialize, Debug, PartialEq, Eq)]
+/// enum Level {
+///     Info,
+///     Debug,
+/// }
+///
+/// serde_default!(Level);
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(default = "default_level::<{ Level::Debug }>")]
+///     level: Level,
+/// }
+///
+/// let config: Config = serde_json::from_str("{}").unwrap();
+/// assert_eq!(config.level, Level::Debug);
+/// ```
+#[cfg(feature = "nightly")]
+pub use core::marker::ConstParamTy;
+
+/// Checks for the value of [`default_str`], made for `skip_serializing_if`
+#[cfg(feature = "nightly")]
+pub fn is_default_str<const V: &'static str>(value: &&str) -> bool {
+    *value == V
+}
+
+/// [`Range`](core::ops::Range) default, see [`default_range_u16`] and friends for other integers
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_eq!(default_range::<2, 5>(), 2..5);
+/// assert_eq!(default_range_inclusive::<2, 5>(), 2..=5);
+/// ```
+pub const fn default_range<const START: usize, const END: usize>() -> core::ops::Range<usize> {
+    START..END
+}
+
+/// [`RangeInclusive`](core::ops::RangeInclusive) default, see [`default_range`]
+pub const fn default_range_inclusive<const START: usize, const END: usize>(
+) -> core::ops::RangeInclusive<usize> {
+    START..=END
+}
+
+macro_rules! serde_default_range {
+    ($($kind:ident),* $(,)?) => {
+        $crate::__private::paste! {
+            $(
+                #[doc = "Same as [`default_range`], but for `" $kind "`"]
+                pub const fn [<default_range_ $kind>]<const START: $kind, const END: $kind>() -> ::core::ops::Range<$kind> {
+                    START..END
+                }
+
+                #[doc = "Same as [`default_range_inclusive`], but for `" $kind "`"]
+                pub const fn [<default_range_inclusive_ $kind>]<const START: $kind, const END: $kind>() -> ::core::ops::RangeInclusive<$kind> {
+                    START..=END
+                }
+            )*
+        }
+    };
+}
+
+serde_default_range!(u8, u16, u32, u64, u128, isize, i8, i16, i32, i64, i128);
+
+/// [`Duration`](core::time::Duration) default in seconds
+/// ```rust
+/// use serde_default_utils::*;
+/// use std::time::Duration;
+///
+/// assert_eq!(default_duration_secs::<30>(), Duration::from_secs(30));
+/// ```
+pub const fn default_duration_secs<const SECS: u64>() -> core::time::Duration {
+    core::time::Duration::from_secs(SECS)
+}
+
+/// [`Duration`](core::time::Duration) default in milliseconds
+/// ```rust
+/// use serde_default_utils::*;
+/// use std::time::Duration;
+///
+/// assert_eq!(default_duration_millis::<1500>(), Duration::from_millis(1500));
+/// ```
+pub const fn default_duration_millis<const MILLIS: u64>() -> core::time::Duration {
+    core::time::Duration::from_millis(MILLIS)
+}
+
+/// [`Duration`](core::time::Duration) default in minutes, fails to compile on overflow
+/// ```rust
+/// use serde_default_utils::*;
+/// use std::time::Duration;
+///
+/// assert_eq!(default_duration_mins::<5>(), Duration::from_secs(5 * 60));
+/// ```
+pub const fn default_duration_mins<const MINS: u64>() -> core::time::Duration {
+    const { duration_from_secs_mul(MINS, 60) }
+}
+
+/// [`Duration`](core::time::Duration) default in hours, fails to compile on overflow
+/// ```rust
+/// use serde_default_utils::*;
+/// use std::time::Duration;
+///
+/// assert_eq!(default_duration_hours::<12>(), Duration::from_secs(12 * 60 * 60));
+/// ```
+pub const fn default_duration_hours<const HOURS: u64>() -> core::time::Duration {
+    const { duration_from_secs_mul(HOURS, 60 * 60) }
+}
+
+/// [`Duration`](core::time::Duration) default in days, fails to compile on overflow
+/// ```rust
+/// use serde_default_utils::*;
+/// use std::time::Duration;
+///
+/// assert_eq!(default_duration_days::<30>(), Duration::from_secs(30 * 24 * 60 * 60));
+/// ```
+/// ```compile_fail
+/// let _ = serde_default_utils::default_duration_days::<{ u64::MAX }>();
+/// ```
+pub const fn default_duration_days<const DAYS: u64>() -> core::time::Duration {
+    const { duration_from_secs_mul(DAYS, 24 * 60 * 60) }
+}
+
+/// [`SystemTime`](std::time::SystemTime) default as seconds since the unix epoch
+/// ```rust
+/// use serde_default_utils::*;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(default_system_time::<1_700_000_000>(), UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+/// ```
+#[cfg(feature = "std")]
+pub fn default_system_time<const EPOCH_SECS: u64>() -> std::time::SystemTime {
+    std::time::UNIX_EPOCH + core::time::Duration::from_secs(EPOCH_SECS)
+}
+
+const fn duration_from_secs_mul(value: u64, unit: u64) -> core::time::Duration {
+    match value.checked_mul(unit) {
+        Some(secs) => core::time::Duration::from_secs(secs),
+        None => panic!("duration default overflows u64 seconds"),
+    }
+}
+
+/// Typed `None` default, pairs with [`is_none`] for `skip_serializing_if`
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_eq!(default_none::<u8>(), None);
+/// ```
+pub const fn default_none<T>() -> Option<T> {
+    None
+}
+
+/// Skip predicate matching [`default_none`]
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert!(is_none::<u8>(&None));
+/// assert!(!is_none(&Some(1)));
+/// ```
+pub const fn is_none<T>(value: &Option<T>) -> bool {
+    value.is_none()
+}
+
+/// Floats can't be const generic parameters, so this takes the bit pattern instead.
+/// Use [`f64_bits`] to get one from a literal
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_eq!(default_f64_bits::<{ f64_bits!(1.5) }>(), 1.5);
+/// ```
+pub const fn default_f64_bits<const BITS: u64>() -> f64 {
+    f64::from_bits(BITS)
+}
+
+/// Floats can't be const generic parameters, so this takes the bit pattern instead.
+/// Use [`f32_bits`] to get one from a literal
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_eq!(default_f32_bits::<{ f32_bits!(-0.25) }>(), -0.25);
+/// ```
+pub const fn default_f32_bits<const BITS: u32>() -> f32 {
+    f32::from_bits(BITS)
+}
+
+/// Float default as a `NUM / DEN` fraction, reads nicer than bits for simple values
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_eq!(default_f64_ratio::<3, 2>(), 1.5);
+/// ```
+///
+/// A zero denominator fails to compile instead of giving `inf` or `NaN`
+/// ```compile_fail
+/// let _ = serde_default_utils::default_f64_ratio::<1, 0>();
+/// ```
+pub const fn default_f64_ratio<const NUM: i64, const DEN: i64>() -> f64 {
+    const { assert!(DEN != 0, "ratio denominator can't be zero") };
+    NUM as f64 / DEN as f64
+}
+
+/// Float default as a `NUM / DEN` fraction, reads nicer than bits for simple values
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_eq!(default_f32_ratio::<-1, 4>(), -0.25);
+/// ```
+///
+/// A zero denominator fails to compile instead of giving `inf` or `NaN`
+/// ```compile_fail
+/// let _ = serde_default_utils::default_f32_ratio::<0, 0>();
+/// ```
+pub const fn default_f32_ratio<const NUM: i32, const DEN: i32>() -> f32 {
+    const { assert!(DEN != 0, "ratio denominator can't be zero") };
+    NUM as f32 / DEN as f32
+}
+
+/// Converts a float literal to its bit pattern at compile time, see [`default_f64_bits`]
+#[macro_export]
+macro_rules! f64_bits {
+    ($value:expr) => {
+        ::core::primitive::f64::to_bits($value)
+    };
+}
+
+/// Converts a float literal to its bit pattern at compile time, see [`default_f32_bits`]
+#[macro_export]
+macro_rules! f32_bits {
+    ($value:expr) => {
+        ::core::primitive::f32::to_bits($value)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use expect_test::expect;
+    use serde::{Deserialize, Serialize};
+    #[cfg(feature = "inline")]
+    use serde_inline_default::serde_inline_default;
+
+    serde_default!(hey, "You matter");
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct Config {
+        #[serde(default = "default_bool::<true>")]
+        yes_or_no: bool,
+        #[serde(default = "default_i32::<-3>")]
+        delta: i32,
+        #[serde(default = "default_u32::<7>")]
+        max: u32,
+        #[serde(default = "default_char::<'☀'>")]
+        delimeter: char,
+        #[serde(default = "default_hey")]
+        motto: &'static str,
+    }
+
+    #[cfg(feature = "inline")]
+    #[serde_inline_default]
+    #[derive(Serialize, Deserialize, Default)]
+    struct InlineConfig {
+        #[serde_inline_default("You matter even more".to_string())]
+        inline_motto: String,
+        #[serde_inline_default(vec!["You".into(), "matter".into(), "even".into(), "more".into()])]
+        slice: Vec<String>,
+        #[serde_inline_default(vec![98712, 12346, 129389, 102937])]
+        slice_u64: Vec<u64>,
+    }
+
+    const JSON: &str = r#"{
+            "yes_or_no":false,
+            "max":60,
+            "delta":-77,
+            "delimeter":"☀",
+            "motto":"I matter"
+        }"#;
+    #[cfg(feature = "inline")]
+    const INLINE_JSON: &str = r#"{
+            "inline_motto":"I matter even more",
+            "slice":["I", "matter", "even", "more"],
+            "slice_u64":[9000, 8000, 10000, 7000]
+        }"#;
+    const EMPTY_JSON: &str = r#"{}"#;
+
+    #[test]
+    fn deserialization_works() {
+        let config: Config = serde_json::from_str(JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"yes_or_no":false,"delta":-77,"max":60,"delimeter":"☀","motto":"I matter"}"#]]
+            .assert_eq(&s);
+        let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"yes_or_no":true,"delta":-3,"max":7,"delimeter":"☀","motto":"You matter"}"#]]
+            .assert_eq(&s);
+        let config = Config::default();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"yes_or_no":false,"delta":0,"max":0,"delimeter":"\u0000","motto":""}"#]]
+            .assert_eq(&s);
+
+        #[cfg(feature = "inline")]
+        {
+            let config: InlineConfig = serde_json::from_str(INLINE_JSON).unwrap();
+            let s = serde_json::to_string(&config).unwrap();
+            expect![[r#"{"inline_motto":"I matter even more","slice":["I","matter","even","more"],"slice_u64":[9000,8000,10000,7000]}"#]]
+        .assert_eq(&s);
+            let config: InlineConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+            let s = serde_json::to_string(&config).unwrap();
+            expect![[
+                r#"{"inline_motto":"You matter even more","slice":["You","matter","even","more"],"slice_u64":[98712,12346,129389,102937]}"#
+            ]].assert_eq(&s);
+
+            let config = InlineConfig::default();
+            let s = serde_json::to_string(&config).unwrap();
+            expect![[r#"{"inline_motto":"","slice":[],"slice_u64":[]}"#]].assert_eq(&s);
+        }
+    }
+
+    #[test]
+    fn owned_string_works() {
+        serde_default!(min_version, String = "1.0.0");
+
+        #[derive(Serialize, Deserialize)]
+        struct OwnedConfig {
+            #[serde(default = "default_min_version")]
+            min_version: String,
+        }
+
+        let config: OwnedConfig = serde_json::from_str(r#"{"min_version":"2.1.0"}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"min_version":"2.1.0"}"#]].assert_eq(&s);
+        let config: OwnedConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"min_version":"1.0.0"}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn shared_str_works() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        serde_default!(region, Box<str> = "eu-west-1");
+        serde_default!(shared_region, Arc<str> = "eu-west-1");
+        serde_default!(local_region, Rc<str> = "eu-west-1");
+
+        #[derive(Serialize, Deserialize)]
+        struct RegionConfig {
+            #[serde(default = "default_region")]
+            region: Box<str>,
+        }
+
+        let config: RegionConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"region":"eu-west-1"}"#]].assert_eq(&s);
+        assert_eq!(default_shared_region(), Arc::from("eu-west-1"));
+        assert_eq!(default_local_region(), Rc::from("eu-west-1"));
+    }
+
+    #[test]
+    fn float_bits_works() {
+        #[derive(Serialize, Deserialize)]
+        struct FloatConfig {
+            #[serde(default = "default_f64_bits::<{ f64_bits!(1.5) }>")]
+            ratio: f64,
+            #[serde(default = "default_f32_bits::<{ f32_bits!(-0.25) }>")]
+            offset: f32,
+        }
+
+        let config: FloatConfig = serde_json::from_str(r#"{"ratio":0.5,"offset":2.0}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"ratio":0.5,"offset":2.0}"#]].assert_eq(&s);
+        let config: FloatConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"ratio":1.5,"offset":-0.25}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn float_ratio_works() {
+        #[derive(Serialize, Deserialize)]
+        struct RatioConfig {
+            #[serde(default = "default_f64_ratio::<3, 2>")]
+            ratio: f64,
+            #[serde(default = "default_f32_ratio::<-1, 4>")]
+            offset: f32,
+        }
+
+        let config: RatioConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"ratio":1.5,"offset":-0.25}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn some_wrapping_works() {
+        #[derive(Serialize, Deserialize)]
+        struct OptionalConfig {
+            #[serde(default = "default_some_u32::<30>")]
+            timeout: Option<u32>,
+            #[serde(default = "default_some_bool::<true>")]
+            verbose: Option<bool>,
+        }
+
+        let config: OptionalConfig =
+            serde_json::from_str(r#"{"timeout":null,"verbose":false}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"timeout":null,"verbose":false}"#]].assert_eq(&s);
+        let config: OptionalConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"timeout":30,"verbose":true}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn array_works() {
+        #[derive(Serialize, Deserialize)]
+        struct ArrayConfig {
+            #[serde(default = "default_array_u8::<0, 32>")]
+            key: [u8; 32],
+            #[serde(default = "default_array_u64::<500, 4>")]
+            retries: [u64; 4],
+        }
+
+        let config: ArrayConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        assert_eq!(config.key, [0; 32]);
+        let s = serde_json::to_string(&config.retries).unwrap();
+        expect![[r#"[500,500,500,500]"#]].assert_eq(&s);
+        assert_eq!(default_array_bool::<true, 2>(), [true, true]);
+    }
+
+    #[test]
+    fn vec_works() {
+        const FALLBACK: u16 = 9090;
+        serde_default!(ports, vec![8080u16, 8081, 8082]);
+        serde_default!(backup_ports, vec![FALLBACK, 9091u16]);
+        serde_default!(offsets, vec![-1, 0, 1]);
+        serde_default!(hosts, vec!["a.example.com", "b.example.com"]);
+
+        #[derive(Serialize, Deserialize)]
+        struct VecConfig {
+            #[serde(default = "default_ports")]
+            ports: Vec<u16>,
+            #[serde(default = "default_hosts")]
+            hosts: Vec<String>,
+        }
+
+        let config: VecConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"ports":[8080,8081,8082],"hosts":["a.example.com","b.example.com"]}"#]]
+            .assert_eq(&s);
+        assert_eq!(default_backup_ports(), vec![9090, 9091]);
+        assert_eq!(default_offsets(), vec![-1i32, 0, 1]);
+    }
+
+    #[test]
+    fn map_works() {
+        use std::collections::{BTreeMap, HashMap};
+
+        serde_default!(limits, map { "read" => 100u32, "write" => 10 });
+        serde_default!(sorted_limits, btree_map { "write" => 10u32, "read" => 100 });
+        serde_default!(names, btree_map { 1u8 => "one", 2 => "two" });
+
+        #[derive(Serialize, Deserialize)]
+        struct MapConfig {
+            #[serde(default = "default_sorted_limits")]
+            limits: BTreeMap<String, u32>,
+        }
+
+        let config: MapConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"limits":{"read":100,"write":10}}"#]].assert_eq(&s);
+        assert_eq!(
+            default_limits(),
+            HashMap::from([("read".to_string(), 100), ("write".to_string(), 10)])
+        );
+        assert_eq!(default_names()[&2], "two");
+    }
+
+    #[test]
+    fn set_works() {
+        use std::collections::{BTreeSet, HashSet};
+
+        serde_default!(allowed, set ["GET", "POST"]);
+        serde_default!(sorted_allowed, btree_set ["POST", "GET"]);
+        serde_default!(ports, btree_set [443u16, 80, 443]);
+
+        #[derive(Serialize, Deserialize)]
+        struct SetConfig {
+            #[serde(default = "default_sorted_allowed")]
+            allowed: BTreeSet<String>,
+        }
+
+        let config: SetConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"allowed":["GET","POST"]}"#]].assert_eq(&s);
+        assert_eq!(
+            default_allowed(),
+            HashSet::from(["GET".to_string(), "POST".to_string()])
+        );
+        assert_eq!(default_ports(), BTreeSet::from([80, 443]));
+    }
+
+    #[test]
+    fn slice_works() {
+        serde_default!(bytes, &[1, 2, 3]);
+        serde_default!(names, &["a", "b"]);
+        serde_default!(weights, &[1u32, 2, 3]);
+        serde_default!(deltas, &[-5i64, 0, 5]);
+        serde_default!(ratios, &[0.5, -0.25]);
+
+        assert_eq!(default_bytes(), &[1u8, 2, 3]);
+        assert_eq!(default_names(), &["a", "b"]);
+        assert_eq!(default_weights(), &[1u32, 2, 3]);
+        assert_eq!(default_deltas(), &[-5i64, 0, 5]);
+        assert_eq!(default_ratios(), &[0.5f64, -0.25]);
+        const NAMES: &[&str] = default_names();
+        assert_eq!(NAMES.len(), 2);
+    }
+
+    #[test]
+    fn slice_of_consts_works() {
+        const LOW: u8 = 1;
+        const HIGH: u8 = 255;
+        serde_default!(bounds, &[LOW, HIGH]);
+
+        let bounds: &'static [u8] = default_bounds();
+        assert_eq!(bounds, &[1, 255]);
+    }
+
+    #[test]
+    fn none_and_skip_works() {
+        #[derive(Serialize, Deserialize)]
+        struct NoneConfig {
+            #[serde(default = "default_none::<u32>", skip_serializing_if = "is_none")]
+            limit: Option<u32>,
+            #[serde(default = "default_none", skip_serializing_if = "is_none")]
+            name: Option<String>,
+        }
+
+        let config: NoneConfig = serde_json::from_str(r#"{"limit":5}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"limit":5}"#]].assert_eq(&s);
+        let config: NoneConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn nonzero_works() {
+        use core::num::{NonZeroI8, NonZeroU32};
+
+        #[derive(Serialize, Deserialize)]
+        struct NonZeroConfig {
+            #[serde(default = "default_nonzero_u32::<8>")]
+            workers: NonZeroU32,
+            #[serde(default = "default_nonzero_i8::<-1>")]
+            step: NonZeroI8,
+        }
+
+        let config: NonZeroConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"workers":8,"step":-1}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn duration_works() {
+        use core::time::Duration;
+
+        #[derive(Serialize, Deserialize)]
+        struct TimeoutConfig {
+            #[serde(default = "default_duration_secs::<30>")]
+            connect: Duration,
+            #[serde(default = "default_duration_millis::<1500>")]
+            read: Duration,
+            #[serde(default = "default_duration_mins::<2>")]
+            idle: Duration,
+            #[serde(default = "default_duration_hours::<1>")]
+            ttl: Duration,
+            #[serde(default = "default_duration_days::<7>")]
+            retention: Duration,
+        }
+
+        let config: TimeoutConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"connect":{"secs":30,"nanos":0},"read":{"secs":1,"nanos":500000000},"idle":{"secs":120,"nanos":0},"ttl":{"secs":3600,"nanos":0},"retention":{"secs":604800,"nanos":0}}"#]]
+            .assert_eq(&s);
+    }
+
+    #[cfg(feature = "humantime")]
+    #[test]
+    fn humantime_duration_works() {
+        use core::time::Duration;
+
+        serde_default!(poll_interval, duration "5m30s");
+        serde_default!(backoff, duration "1s 250ms");
+
+        #[derive(Serialize, Deserialize)]
+        struct PollConfig {
+            #[serde(default = "default_poll_interval")]
+            interval: Duration,
+            #[serde(default = "default_backoff")]
+            backoff: Duration,
+        }
+
+        let config: PollConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"interval":{"secs":330,"nanos":0},"backoff":{"secs":1,"nanos":250000000}}"#]]
+            .assert_eq(&s);
+    }
+
+    #[test]
+    fn system_time_works() {
+        use std::time::SystemTime;
+
+        #[derive(Serialize, Deserialize)]
+        struct CertConfig {
+            #[serde(default = "default_system_time::<1_700_000_000>")]
+            not_before: SystemTime,
+        }
+
+        let config: CertConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"not_before":{"secs_since_epoch":1700000000,"nanos_since_epoch":0}}"#]]
+            .assert_eq(&s);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_works() {
+        use chrono::{DateTime, NaiveDate};
+
+        serde_default!(start_date, date "2024-02-29");
+        serde_default!(launch, datetime "2024-01-01T12:30:00.5+02:00");
+
+        assert_eq!(
+            default_start_date(),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+        assert_eq!(
+            default_launch(),
+            DateTime::parse_from_rfc3339("2024-01-01T10:30:00.5Z").unwrap()
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_works() {
+        use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+        serde_default!(release, time::date "2024-02-29");
+        serde_default!(cutoff, time::time "23:59:59.5");
+        serde_default!(epoch, time::datetime "2024-01-01T12:30:00+02:00");
+
+        let date = Date::from_calendar_date(2024, Month::February, 29).unwrap();
+        assert_eq!(default_release(), date);
+        let time = Time::from_hms_milli(23, 59, 59, 500).unwrap();
+        assert_eq!(default_cutoff(), time);
+        let epoch: OffsetDateTime = PrimitiveDateTime::new(
+            Date::from_calendar_date(2024, Month::January, 1).unwrap(),
+            Time::from_hms(12, 30, 0).unwrap(),
+        )
+        .assume_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(default_epoch(), epoch);
+        assert_eq!(default_epoch().offset().whole_hours(), 2);
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_works() {
+        use jiff::{Span, Timestamp, ToSpan};
+
+        serde_default!(created, jiff::timestamp "2024-01-01T12:30:00.5+02:00");
+        serde_default!(meeting, jiff::zoned "2024-01-01T09:00:00+01:00[+01:00]");
+        serde_default!(retention, jiff::span "30 days 12h");
+        serde_default!(standup, jiff::zoned "2024-07-01T09:00:00+02:00[Europe/Berlin]");
+
+        let created: Timestamp = "2024-01-01T10:30:00.5Z".parse().unwrap();
+        assert_eq!(default_created(), created);
+        assert_eq!(
+            default_meeting().timestamp(),
+            "2024-01-01T08:00:00Z".parse::<Timestamp>().unwrap()
+        );
+        let retention: Span = 30.days().hours(12);
+        assert_eq!(default_retention().fieldwise(), retention.fieldwise());
+        assert_eq!(
+            default_standup().time_zone().iana_name(),
+            Some("Europe/Berlin")
+        );
+        assert_eq!(
+            default_standup().timestamp(),
+            "2024-07-01T07:00:00Z".parse::<Timestamp>().unwrap()
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn timezone_works() {
+        serde_default!(tz, timezone "Europe/Berlin");
+        serde_default!(buenos_aires, timezone "America/Argentina/Buenos_Aires");
+
+        assert_eq!(default_tz(), chrono_tz::Europe::Berlin);
+        assert_eq!(
+            default_buenos_aires(),
+            chrono_tz::America::Argentina::Buenos_Aires
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_works() {
+        use uuid::Uuid;
+
+        serde_default!(tenant, uuid "67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+        assert_eq!(
+            default_tenant(),
+            Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8)
+        );
+        assert_eq!(default_uuid_nil(), Uuid::nil());
+    }
+
+    #[cfg(feature = "uuid-v4")]
+    #[test]
+    fn uuid_v4_works() {
+        let first = default_uuid_v4();
+        assert_eq!(first.get_version_num(), 4);
+        assert_ne!(first, default_uuid_v4());
+    }
+
+    #[cfg(feature = "ulid")]
+    #[test]
+    fn ulid_works() {
+        use ulid::Ulid;
+
+        serde_default!(trace, ulid "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+
+        assert_eq!(
+            default_trace(),
+            Ulid::from_string("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap()
+        );
+        assert_ne!(default_ulid_new(), default_ulid_new());
+    }
+
+    #[test]
+    fn path_works() {
+        use std::path::{Path, PathBuf};
+
+        serde_default!(log_dir, path "/var/log/app");
+        serde_default!(socket_path, &path "/run/app.sock");
+
+        #[derive(Serialize, Deserialize)]
+        struct PathConfig {
+            #[serde(default = "default_log_dir")]
+            log_dir: PathBuf,
+        }
+
+        let config: PathConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"log_dir":"/var/log/app"}"#]].assert_eq(&s);
+        assert_eq!(default_socket_path(), Path::new("/run/app.sock"));
+    }
+
+    #[test]
+    fn os_string_works() {
+        use std::ffi::{OsStr, OsString};
+
+        serde_default!(shell, os_string "/bin/sh");
+        serde_default!(editor, &os_string "vi");
+
+        assert_eq!(default_shell(), OsString::from("/bin/sh"));
+        assert_eq!(default_editor(), OsStr::new("vi"));
+    }
+
+    #[test]
+    fn cstring_works() {
+        use std::ffi::{CStr, CString};
+
+        serde_default!(device, cstring "/dev/ttyUSB0");
+        serde_default!(locale, &cstring "C");
+
+        assert_eq!(default_device(), CString::new("/dev/ttyUSB0").unwrap());
+        assert_eq!(default_locale(), c"C");
+        assert_eq!(default_locale().to_bytes_with_nul(), b"C\0");
+        const LOCALE: &CStr = default_locale();
+        assert_eq!(LOCALE, c"C");
+    }
+
+    #[cfg(feature = "dirs")]
+    #[test]
+    fn dirs_works() {
+        use std::path::PathBuf;
+
+        serde_default!(app_config, config_dir "my-app");
+        serde_default!(app_cache, cache_dir "my-app");
+
+        #[derive(Serialize, Deserialize)]
+        struct DirsConfig {
+            #[serde(default = "default_app_config")]
+            config: Option<PathBuf>,
+            #[serde(default = "default_data_dir")]
+            data: Option<PathBuf>,
+        }
+
+        let config: DirsConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        assert_eq!(config.config, dirs::config_dir().map(|d| d.join("my-app")));
+        assert_eq!(config.data, dirs::data_dir());
+        assert_eq!(
+            default_app_cache(),
+            dirs::cache_dir().map(|d| d.join("my-app"))
+        );
+    }
+
+    #[test]
+    fn current_dirs_works() {
+        use std::path::PathBuf;
+
+        #[derive(Serialize, Deserialize)]
+        struct ToolConfig {
+            #[serde(default = "default_current_dir")]
+            workdir: PathBuf,
+            #[serde(default = "default_exe_dir")]
+            assets: PathBuf,
+        }
+
+        let config: ToolConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        assert_eq!(config.workdir, std::env::current_dir().unwrap());
+        let exe = std::env::current_exe().unwrap();
+        assert_eq!(config.assets, exe.parent().unwrap());
+    }
+
+    #[test]
+    fn ip_works() {
+        use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        serde_default!(bind_ip, ip "0.0.0.0");
+        serde_default!(gateway, ipv4 "192.168.1.254");
+        serde_default!(mapped, ipv6 "::ffff:10.0.0.1");
+
+        #[derive(Serialize, Deserialize)]
+        struct ServerConfig {
+            #[serde(default = "default_bind_ip")]
+            bind: IpAddr,
+            #[serde(default = "default_gateway")]
+            gateway: Ipv4Addr,
+            #[serde(default = "default_mapped")]
+            mapped: Ipv6Addr,
+        }
+
+        let config: ServerConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"bind":"0.0.0.0","gateway":"192.168.1.254","mapped":"::ffff:10.0.0.1"}"#]]
+            .assert_eq(&s);
+    }
+
+    #[test]
+    fn ip_parsing_matches_std() {
+        use core::net::IpAddr;
+
+        for text in [
+            "0.0.0.0",
+            "255.255.255.255",
+            "256.0.0.1",
+            "01.2.3.4",
+            "1.2.3",
+            "1.2.3.4.",
+            "::",
+            "::1",
+            "1::",
+            "1:2:3:4:5:6:7:8",
+            "1:2:3:4:5:6:7::",
+            "1:2:3:4:5:6:7:8:9",
+            "1::2::3",
+            "fe80::1:2",
+            "2001:DB8:0:0:8:800:200C:417A",
+            "::ffff:192.168.0.1",
+            "1:2:3:4:5:6:1.2.3.4",
+            "1:2:3:4:5:6:7:1.2.3.4",
+            "12345::",
+            ":1::",
+            "1:",
+            "",
+        ] {
+            assert_eq!(
+                crate::net::parse_ip(text),
+                text.parse::<IpAddr>().ok(),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn socket_works() {
+        use core::net::SocketAddr;
+
+        serde_default!(listen, socket "0.0.0.0:8080");
+        serde_default!(admin, socket "[::1]:9090");
+
+        #[derive(Serialize, Deserialize)]
+        struct ListenConfig {
+            #[serde(default = "default_listen")]
+            listen: SocketAddr,
+            #[serde(default = "default_admin")]
+            admin: SocketAddr,
+        }
+
+        let config: ListenConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"listen":"0.0.0.0:8080","admin":"[::1]:9090"}"#]].assert_eq(&s);
+
+        for text in [
+            "127.0.0.1:0",
+            "127.0.0.1:65535",
+            "127.0.0.1:65536",
+            "127.0.0.1:080",
+            "127.0.0.1:",
+            "127.0.0.1",
+            ":80",
+            "[::1]:443",
+            "[::1]443",
+            "[::1]",
+            "::1:443",
+            "[1.2.3.4]:80",
+        ] {
+            assert_eq!(
+                crate::net::parse_socket_addr(text),
+                text.parse::<SocketAddr>().ok(),
+                "{text}"
+            );
+        }
+    }
+
+    #[cfg(feature = "ipnet")]
+    #[test]
+    fn cidr_works() {
+        use ipnet::IpNet;
+
+        serde_default!(allowed, cidr "10.0.0.0/8");
+        serde_default!(ula, cidr "fd00::1/8");
+
+        assert_eq!(default_allowed(), "10.0.0.0/8".parse::<IpNet>().unwrap());
+        assert_eq!(default_ula(), "fd00::1/8".parse::<IpNet>().unwrap());
+
+        for text in ["10.0.0.0/33", "10.0.0.0", "10.0.0.0/", "::/129", "::/128"] {
+            assert_eq!(
+                crate::net::parse_ipnet(text),
+                text.parse::<IpNet>().ok(),
+                "{text}"
+            );
+        }
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn url_works() {
+        serde_default!(endpoint, url "https://api.example.com/v1");
+
+        let endpoint = default_endpoint();
+        assert_eq!(endpoint.as_str(), "https://api.example.com/v1");
+        assert_eq!(endpoint.host_str(), Some("api.example.com"));
+    }
+
+    #[cfg(feature = "email")]
+    #[test]
+    fn email_works() {
+        serde_default!(admin_email, email "admin@example.com");
+
+        let email = default_admin_email();
+        assert_eq!(email.as_str(), "admin@example.com");
+        assert_eq!(email.domain(), "example.com");
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimal_works() {
+        use core::str::FromStr;
+        use rust_decimal::Decimal;
+
+        serde_default!(fee, decimal "0.015");
+        serde_default!(credit, decimal "-1_000.50");
+
+        assert_eq!(default_fee(), Decimal::from_str("0.015").unwrap());
+        assert_eq!(default_fee().scale(), 3);
+        assert_eq!(default_credit(), Decimal::from_str("-1000.50").unwrap());
+        assert_eq!(default_credit().to_string(), "-1000.50");
+    }
+
+    #[cfg(feature = "bigdecimal")]
+    #[test]
+    fn bigdecimal_works() {
+        use bigdecimal::BigDecimal;
+        use core::str::FromStr;
+
+        serde_default!(balance, bigdecimal "123456789012345678901234567890.000001");
+        serde_default!(tiny, bigdecimal "1e-40");
+
+        assert_eq!(
+            default_balance(),
+            BigDecimal::from_str("123456789012345678901234567890.000001").unwrap()
+        );
+        assert_eq!(default_tiny(), BigDecimal::new(1.into(), 40));
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn bigint_works() {
+        use num_bigint::{BigInt, BigUint};
+
+        serde_default!(modulus, biguint "340282366920938463463374607431768211507");
+        serde_default!(offset, bigint "-340282366920938463463374607431768211507");
+
+        assert_eq!(default_modulus(), BigUint::from(u128::MAX) + 52u32);
+        assert_eq!(default_offset(), -BigInt::from(default_modulus()));
+    }
+
+    #[cfg(feature = "num-rational")]
+    #[test]
+    fn ratio_works() {
+        use num_rational::Ratio;
+
+        serde_default!(ratio, ratio "6/8");
+        serde_default!(negative, ratio "3/-9");
+        serde_default!(whole, ratio "7");
+
+        assert_eq!(default_ratio(), Ratio::new(6, 8));
+        assert_eq!(*default_ratio().numer(), 3);
+        assert_eq!(default_negative(), Ratio::new(-1, 3));
+        assert_eq!(*default_negative().denom(), 3);
+        assert_eq!(default_whole(), Ratio::from_integer(7));
+    }
+
+    #[cfg(feature = "ordered-float")]
+    #[test]
+    fn ordered_float_works() {
+        use ordered_float::{NotNan, OrderedFloat};
+
+        serde_default!(weight, ordered 0.5);
+        serde_default!(missing, ordered f64::NAN);
+        serde_default!(threshold, not_nan 0.25);
+
+        assert_eq!(default_weight(), OrderedFloat(0.5));
+        assert!(default_missing() > OrderedFloat(f64::INFINITY));
+        assert_eq!(default_threshold(), NotNan::new(0.25).unwrap());
+    }
+
+    #[cfg(feature = "indexmap")]
+    #[test]
+    fn indexmap_works() {
+        use indexmap::{IndexMap, IndexSet};
+
+        serde_default!(steps, index_map { "test" => 2u32, "build" => 1 });
+        serde_default!(stages, index_set ["test", "build"]);
+
+        let steps: IndexMap<String, u32> = default_steps();
+        assert_eq!(steps.keys().collect::<Vec<_>>(), ["test", "build"]);
+        let stages: IndexSet<String> = default_stages();
+        assert_eq!(stages.iter().collect::<Vec<_>>(), ["test", "build"]);
+    }
+
+    #[cfg(feature = "smallvec")]
+    #[test]
+    fn smallvec_works() {
+        serde_default!(peers, smallvec<4> [7001u16, 7002]);
+        serde_default!(hosts, smallvec<1> ["a", "b"]);
+
+        assert_eq!(default_peers().as_slice(), &[7001, 7002]);
+        assert!(!default_peers().spilled());
+        assert!(default_hosts().spilled());
+    }
+
+    #[cfg(feature = "arrayvec")]
+    #[test]
+    fn arrayvec_works() {
+        serde_default!(retries, arrayvec<8> [100u64, 200, 400]);
+
+        let retries = default_retries();
+        assert_eq!(retries.as_slice(), &[100, 200, 400]);
+        assert_eq!(retries.capacity(), 8);
+    }
+
+    #[cfg(feature = "nonempty")]
+    #[test]
+    fn nonempty_works() {
+        use nonempty::NonEmpty;
+
+        serde_default!(seeds, nonempty ["seed-1:7000", "seed-2:7000"]);
+        serde_default!(weights, nonempty[1u8]);
+
+        assert_eq!(
+            default_seeds(),
+            NonEmpty::from(("seed-1:7000".to_string(), vec!["seed-2:7000".to_string()]))
+        );
+        assert_eq!(default_weights(), NonEmpty::new(1));
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_works() {
+        serde_default!(greeting, bytes b"hello");
+        serde_default!(preamble, bytes [0xca, 0xfe]);
+
+        assert_eq!(default_greeting(), "hello");
+        assert_eq!(default_preamble(), [0xca, 0xfe].as_slice());
+    }
+
+    #[test]
+    fn base64_works() {
+        serde_default!(salt, base64 "aGVsbG8=");
+        serde_default!(unpadded, base64 "aGVsbG8");
+        serde_default!(key, &base64 "AAECAwQ=");
+        serde_default!(empty, &base64 "");
+
+        assert_eq!(default_salt(), b"hello");
+        assert_eq!(default_unpadded(), b"hello");
+        assert_eq!(default_key(), &[0, 1, 2, 3, 4]);
+        assert!(default_empty().is_empty());
+        assert_eq!(crate::encoding::decode_base64::<5>("aGVsbG9="), None);
+        assert_eq!(crate::encoding::decode_base64::<5>("aGVsbG8=="), None);
+        assert_eq!(crate::encoding::decode_base64::<3>("a*Vs"), None);
+    }
+
+    #[test]
+    fn hex_works() {
+        serde_default!(magic, &hex "deadBEEF");
+        serde_default!(node_id, hex "00ff00ff");
+
+        assert_eq!(default_magic(), &[0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(default_node_id(), vec![0, 0xff, 0, 0xff]);
+        assert_eq!(crate::encoding::decode_hex::<1>("0g"), None);
+        assert_eq!(crate::encoding::decode_hex::<1>("abc"), None);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_works() {
+        serde_default!(extra, json!({"retries": 3, "tags": []}));
+
+        #[derive(Serialize, Deserialize)]
+        struct JsonConfig {
+            #[serde(default = "default_extra")]
+            extra: serde_json::Value,
+        }
+
+        let config: JsonConfig = serde_json::from_str(r#"{"extra":null}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"extra":null}"#]].assert_eq(&s);
+        let config: JsonConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"extra":{"retries":3,"tags":[]}}"#]].assert_eq(&s);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_works() {
+        serde_default!(labels, yaml "app: web\ntier: frontend");
+        serde_default!(ports, yaml "[80, 443]");
+
+        let labels = default_labels();
+        assert_eq!(labels["app"], "web");
+        assert_eq!(labels["tier"], "frontend");
+        assert_eq!(default_ports()[1], 443);
+    }
+
+    #[test]
+    fn variant_works() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum LogLevel {
+            Info,
+            Debug,
+        }
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum Mode {
+            Fast,
+        }
+
+        serde_default_variant!(LogLevel::Info, LogLevel::Debug, Mode::Fast);
+
+        #[derive(Serialize, Deserialize)]
+        struct VariantConfig {
+            #[serde(default = "default_loglevel_info")]
+            level: LogLevel,
+            #[serde(default = "default_mode_fast")]
+            mode: Mode,
+        }
+
+        let config: VariantConfig = serde_json::from_str(r#"{"level":"Debug"}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"level":"Debug","mode":"Fast"}"#]].assert_eq(&s);
+        let config: VariantConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"level":"Info","mode":"Fast"}"#]].assert_eq(&s);
+        assert_eq!(default_loglevel_debug(), LogLevel::Debug);
+    }
+
+    #[test]
+    fn unknown_as_default_works() {
+        #[derive(Serialize, Deserialize, Default, Debug, PartialEq)]
+        enum Tier {
+            #[default]
+            Free,
+            Pro,
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct Account {
+            #[serde(deserialize_with = "unknown_as_default::deserialize")]
+            tier: Tier,
+        }
+
+        let account: Account = serde_json::from_str(r#"{"tier":"Pro"}"#).unwrap();
+        let s = serde_json::to_string(&account).unwrap();
+        expect![[r#"{"tier":"Pro"}"#]].assert_eq(&s);
+        let account: Account = serde_json::from_str(r#"{"tier":"Enterprise"}"#).unwrap();
+        let s = serde_json::to_string(&account).unwrap();
+        expect![[r#"{"tier":"Free"}"#]].assert_eq(&s);
+        let account: Account = serde_json::from_str(r#"{"tier":1}"#).unwrap();
+        let s = serde_json::to_string(&account).unwrap();
+        expect![[r#"{"tier":"Pro"}"#]].assert_eq(&s);
+        let account: Account = serde_json::from_str(r#"{"tier":7}"#).unwrap();
+        let s = serde_json::to_string(&account).unwrap();
+        expect![[r#"{"tier":"Free"}"#]].assert_eq(&s);
+        assert!(serde_json::from_str::<Account>(r#"{"tier":{"Pro":1}}"#).is_err());
+    }
+
+    #[test]
+    fn wrapping_works() {
+        use core::num::{Saturating, Wrapping};
+
+        #[derive(Serialize, Deserialize)]
+        struct CounterConfig {
+            #[serde(default = "default_wrapping_u8::<255>")]
+            sequence: Wrapping<u8>,
+            #[serde(default = "default_saturating_i32::<-5>")]
+            balance: Saturating<i32>,
+        }
+
+        let config: CounterConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"sequence":255,"balance":-5}"#]].assert_eq(&s);
+        assert_eq!(config.sequence + Wrapping(1), Wrapping(0));
+        assert_eq!(
+            default_saturating_u8::<250>() + Saturating(10),
+            Saturating(255)
+        );
+    }
+
+    #[test]
+    fn range_works() {
+        use core::ops::{Range, RangeInclusive};
+
+        #[derive(Serialize, Deserialize)]
+        struct RangeConfig {
+            #[serde(default = "default_range_u16::<8000, 9000>")]
+            ports: Range<u16>,
+            #[serde(default = "default_range_inclusive::<1, 3>")]
+            retries: RangeInclusive<usize>,
+        }
+
+        let config: RangeConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"ports":{"start":8000,"end":9000},"retries":{"start":1,"end":3}}"#]]
+            .assert_eq(&s);
+        assert_eq!(default_range::<0, 10>().len(), 10);
+        assert!(default_range_inclusive_i8::<-1, 1>().contains(&0));
+    }
+
+    #[test]
+    fn tuple_works() {
+        serde_default!(window, (800u32, 600u32));
+        serde_default!(origin, (0.5, "center", -1i8));
+
+        #[derive(Serialize, Deserialize)]
+        struct WindowConfig {
+            #[serde(default = "default_window")]
+            size: (u32, u32),
+        }
+
+        let config: WindowConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"size":[800,600]}"#]].assert_eq(&s);
+        assert_eq!(default_origin(), (0.5, "center", -1));
+    }
+
+    #[cfg(feature = "either")]
+    #[test]
+    fn either_works() {
+        use either::Either;
+
+        serde_default!(limit, Either<u32, String> = Left(10));
+        serde_default!(fallback, Either<u32, String> = Right("unlimited"));
+
+        assert_eq!(default_limit(), Either::Left(10));
+        assert_eq!(default_fallback(), Either::Right("unlimited".to_string()));
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn heapless_works() {
+        serde_default!(boot, heapless::String<16> = "boot");
+        serde_default!(pins, heapless::Vec<u8, 4> = [2, 3]);
+
+        assert_eq!(default_boot(), "boot");
+        assert_eq!(default_boot().capacity(), 16);
+        assert_eq!(default_pins(), [2, 3]);
+        assert_eq!(default_pins().capacity(), 4);
+    }
+
+    #[cfg(feature = "smol_str")]
+    #[test]
+    fn smol_str_works() {
+        serde_default!(zone, SmolStr = "eu-west-1a");
+        serde_default!(
+            description,
+            SmolStr = "a string that is longer than the inline capacity"
+        );
+
+        assert_eq!(default_zone(), "eu-west-1a");
+        assert!(!default_zone().is_heap_allocated());
+        assert!(!default_description().is_heap_allocated());
+    }
+
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn compact_str_works() {
+        serde_default!(rack, CompactString = "r42");
+
+        assert_eq!(default_rack(), "r42");
+        assert!(!default_rack().is_heap_allocated());
+    }
+
+    #[cfg(feature = "bitflags")]
+    #[test]
+    fn bitflags_works() {
+        bitflags::bitflags! {
+            #[derive(Debug, PartialEq)]
+            pub struct Perms: u8 {
+                const READ = 1;
+                const WRITE = 1 << 1;
+                const EXEC = 1 << 2;
+            }
+        }
+
+        serde_default!(perms, Perms::READ | Perms::WRITE);
+        serde_default!(exec, Perms::EXEC);
+
+        assert_eq!(default_perms(), Perms::READ | Perms::WRITE);
+        assert_eq!(default_exec(), Perms::EXEC);
+        const PERMS: Perms = default_perms();
+        assert_eq!(PERMS.bits(), 0b11);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_works() {
+        serde_default!(pattern, regex r"^\w+$");
+
+        assert!(default_pattern().is_match("hello"));
+        assert!(!default_pattern().is_match("hello world"));
+        assert_eq!(default_pattern().as_str(), r"^\w+$");
+    }
+
+    #[cfg(feature = "cron")]
+    #[test]
+    fn cron_works() {
+        use cron::TimeUnitSpec;
+
+        serde_default!(schedule, cron "0 0 * * * *");
+
+        let schedule = default_schedule();
+        assert_eq!(schedule.source(), "0 0 * * * *");
+        assert_eq!(schedule.seconds().count(), 1);
+        assert_eq!(schedule.hours().count(), 24);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_level_works() {
+        use log::LevelFilter;
+
+        serde_default!(log_level, log_level "info");
+        serde_default!(quiet, log_level "OFF");
+
+        assert_eq!(default_log_level(), LevelFilter::Info);
+        assert_eq!(default_quiet(), LevelFilter::Off);
+        for level in ["off", "error", "warn", "info", "debug", "trace"] {
+            let parsed = crate::levels::parse_level_filter(level);
+            assert_eq!(parsed, level.parse().ok());
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn tracing_level_works() {
+        use tracing::Level;
+
+        serde_default!(trace_level, tracing_level "Debug");
+
+        assert_eq!(default_trace_level(), Level::DEBUG);
+        for level in ["error", "warn", "info", "debug", "trace"] {
+            let parsed = crate::levels::parse_tracing_level(level);
+            assert_eq!(parsed, level.parse().ok());
+        }
+        assert_eq!(crate::levels::parse_tracing_level("off"), None);
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn http_works() {
+        use http::{HeaderValue, Method, StatusCode};
+
+        serde_default!(status, status 503);
+        serde_default!(method, method "POST");
+        serde_default!(custom_method, method "PURGE");
+        serde_default!(user_agent, header_value "my-proxy/1.0");
+
+        assert_eq!(default_status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(default_method(), Method::POST);
+        assert_eq!(default_custom_method().as_str(), "PURGE");
+        assert_eq!(
+            default_user_agent(),
+            HeaderValue::from_static("my-proxy/1.0")
+        );
+    }
+
+    #[cfg(feature = "mime")]
+    #[test]
+    fn mime_works() {
+        serde_default!(content_type, mime "application/json");
+        serde_default!(text, mime "text/plain; charset=utf-8");
+
+        assert_eq!(default_content_type(), mime::APPLICATION_JSON);
+        assert_eq!(default_text(), mime::TEXT_PLAIN_UTF_8);
+    }
+
+    #[test]
+    fn bytesize_works() {
+        use crate::num::parse_byte_size;
+
+        serde_default!(max_body, bytesize "10MiB");
+
+        #[derive(Serialize, Deserialize)]
+        struct LimitsConfig {
+            #[serde(default = "default_max_body")]
+            max_body: u64,
+        }
+
+        let config: LimitsConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"max_body":10485760}"#]].assert_eq(&s);
+        assert_eq!(parse_byte_size("512"), Some(512));
+        assert_eq!(parse_byte_size("512b"), Some(512));
+        assert_eq!(parse_byte_size("1.5 GB"), Some(1_500_000_000));
+        assert_eq!(parse_byte_size("1.5GiB"), Some(1_610_612_736));
+        assert_eq!(parse_byte_size("4k"), Some(4_000));
+        assert_eq!(parse_byte_size("4Ki"), Some(4_096));
+        assert_eq!(parse_byte_size("16EiB"), None);
+        assert_eq!(parse_byte_size("MiB"), None);
+        assert_eq!(parse_byte_size("1 XB"), None);
+    }
+
+    #[test]
+    fn percent_works() {
+        use crate::num::parse_percent;
+
+        serde_default!(sample_rate, percent "2.5%");
+        serde_default!(threshold, percent100 "80%");
+
+        assert_eq!(default_sample_rate(), 0.025);
+        assert_eq!(default_threshold(), 80.0);
+        assert_eq!(parse_percent("100%", false), Some(1.0));
+        assert_eq!(parse_percent("0.1", false), Some(0.001));
+        assert_eq!(parse_percent("33.3%", true), Some(33.3));
+        assert_eq!(parse_percent("100.5%", false), None);
+        assert_eq!(parse_percent("-1%", true), None);
+        assert_eq!(parse_percent("%", false), None);
+    }
+
+    #[cfg(feature = "money")]
+    #[test]
+    fn money_works() {
+        serde_default!(price, money "19.99 USD");
+        serde_default!(refund, money "-5 JPY");
+
+        #[derive(Serialize, Deserialize)]
+        struct BillingConfig {
+            #[serde(default = "default_price")]
+            price: Money,
+        }
+
+        let config: BillingConfig = serde_json::from_str(r#"{"price":"0.50 EUR"}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"price":"0.50 EUR"}"#]].assert_eq(&s);
+        let config: BillingConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"price":"19.99 USD"}"#]].assert_eq(&s);
+        assert_eq!(default_refund().to_string(), "-5 JPY");
+        assert!(serde_json::from_str::<BillingConfig>(r#"{"price":"19.99 usd"}"#).is_err());
+        assert_eq!(Money::parse("USD"), None);
+        assert_eq!(Money::parse("1.5USD"), None);
+    }
+
+    #[cfg(feature = "langid")]
+    #[test]
+    fn langid_works() {
+        serde_default!(locale, lang "en-US");
+        serde_default!(script, lang "sr-Latn");
+
+        let locale = default_locale();
+        assert_eq!(locale.language.as_str(), "en");
+        assert_eq!(locale.region.unwrap().as_str(), "US");
+        assert_eq!(default_script().to_string(), "sr-Latn");
+    }
+
+    #[cfg(feature = "secrecy")]
+    #[test]
+    fn secrecy_works() {
+        use secrecy::ExposeSecret;
+
+        serde_default!(api_key, SecretString = "change-me");
+
+        let key = default_api_key();
+        assert_eq!(key.expose_secret(), "change-me");
+        assert!(!format!("{key:?}").contains("change-me"));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_works() {
+        use glam::{IVec2, Vec3};
+
+        serde_default!(gravity, glam::Vec3 = [0.0, -9.81, 0.0]);
+        serde_default!(tile, glam::IVec2 = [4, -2]);
+
+        const GRAVITY: Vec3 = default_gravity();
+        assert_eq!(GRAVITY, Vec3::new(0.0, -9.81, 0.0));
+        assert_eq!(default_tile(), IVec2::new(4, -2));
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_works() {
+        serde_default!(spawn, mint::Point2<i32> = [10, 20]);
+        serde_default!(up, mint::Vector3<f32> = [0.0, 1.0, 0.0]);
+
+        assert_eq!(default_spawn(), mint::Point2 { x: 10, y: 20 });
+        assert_eq!(default_up().y, 1.0);
+    }
+
+    #[cfg(feature = "uom")]
+    #[test]
+    fn uom_works() {
+        use uom::si::{f64::Length, length, time};
+
+        serde_default!(max_height, uom Length = 2.5 meter);
+        serde_default!(timeout, uom Time = 1.5 minute);
+        serde_default!(limit, uom ThermodynamicTemperature = 300.0 kelvin);
+
+        assert_eq!(
+            default_max_height(),
+            Length::new::<length::centimeter>(250.0)
+        );
+        assert_eq!(default_max_height().get::<length::meter>(), 2.5);
+        assert_eq!(default_timeout().get::<time::second>(), 90.0);
+        assert!(default_limit().value > 0.0);
+    }
+
+    #[test]
+    fn octal_works() {
+        serde_default!(mode, octal 0o644);
 
-            let config = InlineConfig::default();
+        #[derive(Serialize, Deserialize)]
+        struct FileConfig {
+            #[serde(default = "default_mode")]
+            mode: u32,
+        }
+
+        let config: FileConfig = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"mode":420}"#]].assert_eq(&s);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn permissions_works() {
+        use std::os::unix::fs::PermissionsExt;
+
+        serde_default!(dir_permissions, permissions 0o755);
+
+        assert_eq!(default_dir_permissions().mode(), 0o755);
+    }
+
+    #[test]
+    fn type_list_works() {
+        #[allow(dead_code)]
+        mod regenerated {
+            pub type Port = u16;
+            serde_default!(Port, u8, bool);
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct Listener {
+            #[serde(default = "regenerated::default_port::<8080>")]
+            port: u16,
+            #[serde(default = "regenerated::default_u8::<3>")]
+            backlog: u8,
+            #[serde(default = "regenerated::default_some_bool::<true>")]
+            reuse: Option<bool>,
+        }
+
+        let config: Listener = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":8080,"backlog":3,"reuse":true}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn visibility_works() {
+        #[allow(dead_code)]
+        mod defaults {
+            serde_default!(pub(super) motto, "You matter");
+            serde_default!(pub(super) retries, (3u8,));
+            serde_default!(pub(super) u8);
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct Config {
+            #[serde(default = "defaults::default_motto")]
+            motto: &'static str,
+            #[serde(default = "defaults::default_retries")]
+            retries: (u8,),
+            #[serde(default = "defaults::default_u8::<5>")]
+            workers: u8,
+        }
+
+        let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"motto":"You matter","retries":[3],"workers":5}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn module_works() {
+        serde_default!(mod server_defaults {
+            port: u16 = 8080,
+            host: "localhost",
+            fallback: Result<u16, &'static str> = Ok(8081),
+            weights: &[1u8, 2],
+        });
+        serde_default!(pub(crate) mod client_defaults { port: u16 = 443 });
+
+        #[derive(Serialize, Deserialize)]
+        struct Server {
+            #[serde(default = "server_defaults::default_port")]
+            port: u16,
+            #[serde(default = "server_defaults::default_host")]
+            host: &'static str,
+            #[serde(default = "client_defaults::default_port")]
+            client_port: u16,
+        }
+
+        let config: Server = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":8080,"host":"localhost","client_port":443}"#]].assert_eq(&s);
+        assert_eq!(server_defaults::default_fallback(), Ok(8081));
+        assert_eq!(server_defaults::default_weights(), &[1, 2]);
+    }
+
+    #[test]
+    fn typed_expr_works() {
+        const MINUTE: u64 = 60;
+        serde_default!(timeout: u64 = 30 * MINUTE);
+        serde_default!(pub(crate) backoff: [u16; 3] = [100, 200, 400]);
+        serde_default!(motto: "You matter");
+
+        #[derive(Serialize, Deserialize)]
+        struct Client {
+            #[serde(default = "default_timeout")]
+            timeout: u64,
+            #[serde(default = "default_backoff")]
+            backoff: [u16; 3],
+            #[serde(default = "default_motto")]
+            motto: &'static str,
+        }
+
+        let config: Client = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"timeout":1800,"backoff":[100,200,400],"motto":"You matter"}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn is_default_works() {
+        #[derive(Serialize, Deserialize)]
+        struct Config {
+            #[serde(
+                default = "default_u16::<8080>",
+                skip_serializing_if = "is_default_u16::<8080>"
+            )]
+            port: u16,
+            #[serde(
+                default = "default_some_bool::<true>",
+                skip_serializing_if = "is_default_some_bool::<true>"
+            )]
+            tls: Option<bool>,
+            #[serde(
+                default = "default_array_u8::<1, 2>",
+                skip_serializing_if = "is_default_array_u8::<1, 2>"
+            )]
+            weights: [u8; 2],
+        }
+
+        let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{}"#]].assert_eq(&s);
+
+        let config: Config =
+            serde_json::from_str(r#"{"port":80,"tls":null,"weights":[1,3]}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":80,"tls":null,"weights":[1,3]}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn pair_works() {
+        serde_default_pair!(port, u16, 8080);
+        serde_default_pair!(pub(crate) motto, &'static str, "You matter");
+
+        #[derive(Serialize, Deserialize)]
+        struct Config {
+            #[serde(default = "default_port", skip_serializing_if = "is_default_port")]
+            port: u16,
+            #[serde(default = "default_motto", skip_serializing_if = "is_default_motto")]
+            motto: &'static str,
+        }
+
+        let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{}"#]].assert_eq(&s);
+
+        let config: Config = serde_json::from_str(r#"{"port":80,"motto":"hey"}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":80,"motto":"hey"}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn custom_name_works() {
+        serde_default!(as max_retries, u32 = 5);
+        serde_default!(pub(crate) as max_connections: u32 = 100);
+        serde_default!(as greeting, String = "hello");
+
+        #[derive(Serialize, Deserialize)]
+        struct Pool {
+            #[serde(default = "max_retries")]
+            retries: u32,
+            #[serde(default = "max_connections")]
+            connections: u32,
+            #[serde(default = "greeting")]
+            greeting: String,
+        }
+
+        let config: Pool = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"retries":5,"connections":100,"greeting":"hello"}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn some_works() {
+        serde_default!(some proxy, "http://localhost:3128");
+        serde_default!(some workers: u16 = 4);
+        serde_default!(some user_agent, String = "my-app/1.0");
+
+        #[derive(Serialize, Deserialize)]
+        struct Client {
+            #[serde(default = "default_proxy")]
+            proxy: Option<&'static str>,
+            #[serde(default = "default_workers")]
+            workers: Option<u16>,
+            #[serde(default = "default_user_agent")]
+            user_agent: Option<String>,
+        }
+
+        let config: Client = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"proxy":"http://localhost:3128","workers":4,"user_agent":"my-app/1.0"}"#]]
+            .assert_eq(&s);
+
+        let config: Client = serde_json::from_str(r#"{"proxy":null}"#).unwrap();
+        assert_eq!(config.proxy, None);
+    }
+
+    #[test]
+    fn check_defaults_works() {
+        serde_default!(motto, "You matter");
+
+        #[check_defaults]
+        #[derive(Serialize, Deserialize)]
+        #[serde(default = "Listener::fallback")]
+        struct Listener<'a> {
+            #[serde(
+                default = "default_u16::<8080>",
+                skip_serializing_if = "is_default_u16::<8080>"
+            )]
+            port: u16,
+            #[serde(default = "default_motto", rename = "banner")]
+            motto: &'a str,
+        }
+
+        impl Listener<'_> {
+            fn fallback() -> Self {
+                Self {
+                    port: 80,
+                    motto: "hey",
+                }
+            }
+        }
+
+        let config: Listener = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"banner":"You matter"}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn grouped_works() {
+        serde_default! {
+            motto: "You matter",
+            greeting: "hello",
+            sep: ",",
+            pair: Result<u8, ()> = Ok(1),
+        }
+        serde_default!(pub(crate) first: "a", second: "b");
+
+        #[derive(Serialize, Deserialize)]
+        struct Banner {
+            #[serde(default = "default_motto")]
+            motto: &'static str,
+            #[serde(default = "default_greeting")]
+            greeting: &'static str,
+            #[serde(default = "default_sep")]
+            sep: &'static str,
+        }
+
+        let config: Banner = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"motto":"You matter","greeting":"hello","sep":","}"#]].assert_eq(&s);
+        assert_eq!(default_pair(), Ok(1));
+        assert_eq!((default_first(), default_second()), ("a", "b"));
+    }
+
+    #[test]
+    fn grouped_with_operators_works() {
+        const LIMIT: u16 = 9000;
+        serde_default! {
+            mask: u32 = 1 << 4,
+            port: u16 = 8080,
+            below: bool = 8080 < LIMIT,
+            capacity: usize = ::core::primitive::usize::pow(2, 3) >> 1,
+            sizes: Vec<u8> = Vec::<u8>::new(),
+            zero: u8 = <u8>::MIN,
+            shift: u32 = 1u32 << 2,
+        }
+
+        assert_eq!(default_mask(), 16);
+        assert_eq!(default_port(), 8080);
+        assert!(default_below());
+        assert_eq!(default_capacity(), 4);
+        assert!(default_sizes().is_empty());
+        assert_eq!(default_zero(), 0);
+        assert_eq!(default_shift(), 4);
+    }
+
+    #[test]
+    fn cfg_works() {
+        serde_default!(socket_path, cfg {
+            unix => "/run/app.sock",
+            windows => r"\\.\pipe\app",
+        });
+        serde_default!(mode, cfg { test => "testing", debug_assertions => "debug", _ => "release" });
+        serde_default!(pointer_bits, cfg { target_pointer_width = "64" => 64u8, _ => 32 });
+        serde_default!(port: u16, cfg { target_os = "linux" => 8080, _ => 80 });
+        serde_default! {
+            workers: u8, cfg { debug_assertions => 1, _ => 8 },
+            label: "cfg",
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct Server {
+            #[serde(default = "default_mode")]
+            mode: &'static str,
+            #[serde(default = "default_pointer_bits")]
+            pointer_bits: u8,
+            #[serde(default = "default_port")]
+            port: u16,
+        }
+
+        let config: Server = serde_json::from_str(EMPTY_JSON).unwrap();
+        assert_eq!(config.mode, "testing");
+        assert_eq!(config.pointer_bits as usize, usize::BITS as usize);
+        assert_eq!(
+            config.port,
+            if cfg!(target_os = "linux") { 8080 } else { 80 }
+        );
+        assert_eq!(
+            default_workers(),
+            if cfg!(debug_assertions) { 1 } else { 8 }
+        );
+        assert_eq!(default_label(), "cfg");
+        #[cfg(unix)]
+        assert_eq!(default_socket_path(), "/run/app.sock");
+    }
+
+    #[test]
+    fn env_works() {
+        serde_default!(api_base, env "SERDE_DEFAULT_UTILS_UNSET_API_BASE", "https://api.example.com");
+        serde_default!(package, env "CARGO_PKG_NAME", "unknown");
+        serde_default!(version, env "CARGO_PKG_VERSION");
+
+        #[derive(Serialize, Deserialize)]
+        struct Status {
+            #[serde(default = "default_api_base")]
+            api_base: &'static str,
+            #[serde(default = "default_package")]
+            package: &'static str,
+        }
+
+        let config: Status = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"api_base":"https://api.example.com","package":"serde_default_utils"}"#]]
+            .assert_eq(&s);
+        assert_eq!(default_version(), env!("CARGO_PKG_VERSION"));
+    }
+
+    #[cfg(feature = "build")]
+    #[test]
+    fn build_works() {
+        serde_default_build!();
+
+        assert_eq!(default_build_git_sha(), "unknown");
+        assert_eq!(default_build_timestamp(), "unknown");
+        assert_eq!(crate::build::rfc3339(0), "1970-01-01T00:00:00Z");
+        assert_eq!(crate::build::rfc3339(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(crate::build::rfc3339(1_700_000_000), "2023-11-14T22:13:20Z");
+        // empty outside a git checkout, like a crates.io tarball
+        for path in crate::build::git_watched() {
+            assert!(std::path::Path::new(&path).exists(), "{path}");
+        }
+    }
+
+    #[test]
+    fn parse_works() {
+        serde_default!(limit, parse u64 "1_000_000");
+        serde_default!(mask, parse u32 "0xFFFF_0000");
+        serde_default!(mode, parse u16 "0o644");
+        serde_default!(floor, parse i8 "-128");
+        serde_default!(huge, parse u128 "340282366920938463463374607431768211455");
+        serde_default!(lowest, parse i128 "-0x8000_0000_0000_0000_0000_0000_0000_0000");
+
+        #[derive(Serialize, Deserialize)]
+        struct Limits {
+            #[serde(default = "default_limit")]
+            limit: u64,
+            #[serde(default = "default_mask")]
+            mask: u32,
+            #[serde(default = "default_mode")]
+            mode: u16,
+            #[serde(default = "default_floor")]
+            floor: i8,
+        }
+
+        let config: Limits = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"limit":1000000,"mask":4294901760,"mode":420,"floor":-128}"#]].assert_eq(&s);
+        assert_eq!(default_huge(), u128::MAX);
+        assert_eq!(default_lowest(), i128::MIN);
+        assert_eq!(crate::num::parse_integer("0x"), None);
+        assert_eq!(crate::num::parse_integer("0b102"), None);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn default_str_works() {
+        #[derive(Serialize, Deserialize)]
+        struct Config {
+            #[serde(
+                default = "default_str::<\"You matter\">",
+                skip_serializing_if = "is_default_str::<\"You matter\">"
+            )]
+            motto: &'static str,
+            #[serde(default = "default_str::<\"hello\">")]
+            greeting: &'static str,
+        }
+
+        let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"greeting":"hello"}"#]].assert_eq(&s);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    #[allow(dead_code)]
+    fn const_param_enum_works() {
+        #[derive(ConstParamTy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+        enum Level {
+            Info,
+            Debug,
+        }
+
+        serde_default!(Level);
+
+        #[derive(Serialize, Deserialize)]
+        struct Config {
+            #[serde(
+                default = "default_level::<{ Level::Debug }>",
+                skip_serializing_if = "is_default_level::<{ Level::Debug }>"
+            )]
+            level: Level,
+            #[serde(default = "default_some_level::<{ Level::Info }>")]
+            fallback: Option<Level>,
+        }
+
+        let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"fallback":"Info"}"#]].assert_eq(&s);
+        assert_eq!(
+            default_array_level::<{ Level::Info }, 2>(),
+            [Level::Info, Level::Info]
+        );
+    }
+
+    #[test]
+    fn lazy_works() {
+        use std::collections::BTreeMap;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static BUILDS: AtomicUsize = AtomicUsize::new(0);
+        serde_default_lazy!(limits, BTreeMap<String, u32> = {
+            BUILDS.fetch_add(1, Ordering::Relaxed);
+            BTreeMap::from([("read".to_owned(), 100), ("write".to_owned(), 10)])
+        });
+        serde_default_lazy!(template, &String = "Hello, {name}!".to_owned());
+
+        #[derive(Serialize, Deserialize)]
+        struct Config {
+            #[serde(default = "default_limits")]
+            limits: BTreeMap<String, u32>,
+        }
+
+        for _ in 0..3 {
+            let config: Config = serde_json::from_str(EMPTY_JSON).unwrap();
             let s = serde_json::to_string(&config).unwrap();
-            expect![[r#"{"inline_motto":"","slice":[],"slice_u64":[]}"#]].assert_eq(&s);
+            expect![[r#"{"limits":{"read":100,"write":10}}"#]].assert_eq(&s);
+        }
+        assert_eq!(BUILDS.load(Ordering::Relaxed), 1);
+        assert!(std::ptr::eq(default_template(), default_template()));
+    }
+
+    #[test]
+    fn derive_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize, Debug, PartialEq)]
+        struct Server {
+            #[default(8080)]
+            #[serde(default = "Server::default_port")]
+            port: u16,
+            #[default("0.0.0.0")]
+            #[serde(default = "Server::default_host")]
+            host: String,
+            #[default(vec![1, 2])]
+            #[serde(default = "Server::default_weights")]
+            weights: Vec<u8>,
+            #[serde(default)]
+            verbose: bool,
+        }
+
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde(default)]
+        struct Client<T: Default> {
+            #[default(3)]
+            retries: u32,
+            extra: T,
+        }
+
+        let config: Server = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":8080,"host":"0.0.0.0","weights":[1,2],"verbose":false}"#]]
+            .assert_eq(&s);
+        assert_eq!(config, Server::default());
+
+        let config: Client<u8> = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"retries":3,"extra":0}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn serde_defaults_works() {
+        mod consts {
+            pub const RETRIES: u32 = 3;
+        }
+
+        #[serde_defaults]
+        #[derive(Serialize, Deserialize)]
+        struct Plain {
+            #[default("eu-west-1")]
+            region: String,
+            verbose: bool,
+            #[serde(default = "default_u8::<9>")]
+            level: u8,
+        }
+
+        #[serde_defaults(consts = consts)]
+        #[derive(Serialize, Deserialize)]
+        struct Client<T> {
+            retries: u32,
+            #[serde(default)]
+            extra: T,
+        }
+
+        let config: Plain = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"region":"eu-west-1","verbose":false,"level":9}"#]].assert_eq(&s);
+        assert_eq!(Plain::default().level, config.level);
+
+        let config: Client<u8> = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"retries":3,"extra":0}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn const_default_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize, Debug, PartialEq)]
+        #[serde_default(const)]
+        #[serde(default)]
+        struct Limits {
+            #[default(100)]
+            requests: u32,
+            #[default(Some(1.5))]
+            burst: Option<f32>,
+            #[default(Vec::new())]
+            tags: Vec<String>,
+        }
+
+        const LIMITS: Limits = Limits::DEFAULT;
+        const _: () = assert!(Limits::default_requests() == 100);
+
+        let config: Limits = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"requests":100,"burst":1.5,"tags":[]}"#]].assert_eq(&s);
+        assert_eq!(config, LIMITS);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn defaults_json_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(json)]
+        struct Pool {
+            #[default(5)]
+            retries: u32,
+            #[default(vec!["a".into()])]
+            tags: Vec<String>,
+            verbose: bool,
+        }
+
+        let s = Pool::defaults_json().to_string();
+        expect![[r#"{"retries":5,"tags":["a"],"verbose":false}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn derive_is_default_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(is_default)]
+        #[serde(default)]
+        struct Pool {
+            #[default(5)]
+            #[serde(skip_serializing_if = "Pool::is_default_retries")]
+            retries: u32,
+            #[default("main")]
+            #[serde(skip_serializing_if = "Pool::is_default_name")]
+            name: String,
+            #[serde(skip_serializing_if = "Pool::is_default_tags")]
+            tags: Vec<String>,
+        }
+
+        let s = serde_json::to_string(&Pool::default()).unwrap();
+        expect![[r#"{}"#]].assert_eq(&s);
+
+        let config: Pool = serde_json::from_str(r#"{"retries":7,"tags":["a"]}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"retries":7,"tags":["a"]}"#]].assert_eq(&s);
+        assert!(Pool::is_default_name(&config.name));
+    }
+
+    #[test]
+    fn partial_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(partial(derive(Debug, Clone, Serialize, Deserialize)))]
+        struct Pool<T> {
+            #[default(5)]
+            retries: u32,
+            #[serde(rename = "pool_name")]
+            #[default("main")]
+            name: String,
+            extra: T,
+        }
+
+        let partial: PoolPartial<u8> = serde_json::from_str(r#"{"pool_name":"backup"}"#).unwrap();
+        let s = serde_json::to_string(&partial).unwrap();
+        expect![[r#"{"pool_name":"backup"}"#]].assert_eq(&s);
+
+        let config = Pool::from(partial.clone());
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"retries":5,"pool_name":"backup","extra":0}"#]].assert_eq(&s);
+
+        let config = Pool::from_partial(PoolPartial {
+            extra: Some(3),
+            ..partial
+        });
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"retries":5,"pool_name":"backup","extra":3}"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn merge_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(merge, partial(derive(Deserialize)))]
+        struct Pool<T> {
+            #[default(5)]
+            retries: u32,
+            #[default("main")]
+            name: String,
+            extra: T,
+        }
+
+        let mut base: Pool<u8> =
+            serde_json::from_str(r#"{"retries":1,"name":"base","extra":0}"#).unwrap();
+        base.merge(serde_json::from_str(r#"{"retries":5,"name":"override","extra":2}"#).unwrap());
+        let s = serde_json::to_string(&base).unwrap();
+        expect![[r#"{"retries":1,"name":"override","extra":2}"#]].assert_eq(&s);
+
+        base.merge_partial(serde_json::from_str(r#"{"retries":5}"#).unwrap());
+        let s = serde_json::to_string(&base).unwrap();
+        expect![[r#"{"retries":5,"name":"override","extra":2}"#]].assert_eq(&s);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn diff_from_default_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(json)]
+        #[serde(default)]
+        struct Tls {
+            #[default(true)]
+            enabled: bool,
+            cert: Option<String>,
+        }
+
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(json)]
+        #[serde(default)]
+        struct Server {
+            #[default(8080)]
+            port: u16,
+            #[default(vec![1, 2])]
+            weights: Vec<u8>,
+            tls: Tls,
+        }
+
+        let s = Server::default().diff_from_default().to_string();
+        expect![[r#"{}"#]].assert_eq(&s);
+
+        let server: Server =
+            serde_json::from_str(r#"{"weights":[1],"tls":{"cert":"a.pem"}}"#).unwrap();
+        let s = server.diff_from_default().to_string();
+        expect![[r#"{"tls":{"cert":"a.pem"},"weights":[1]}"#]].assert_eq(&s);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn merge_patch_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(json)]
+        #[serde(default)]
+        struct Server {
+            #[default(8080)]
+            port: u16,
+            #[default(Some("a.pem".into()))]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            cert: Option<String>,
+            tags: std::collections::BTreeMap<String, String>,
+        }
+
+        let mut server = Server::default();
+        server
+            .apply_merge_patch(
+                serde_json::json!({"port": 9090, "cert": null, "tags": {"env": "prod"}}),
+            )
+            .unwrap();
+        let s = serde_json::to_string(&server).unwrap();
+        expect![[r#"{"port":9090,"cert":"a.pem","tags":{"env":"prod"}}"#]].assert_eq(&s);
+
+        let s = server.merge_patch_from_default().to_string();
+        expect![[r#"{"port":9090,"tags":{"env":"prod"}}"#]].assert_eq(&s);
+
+        server
+            .apply_merge_patch(serde_json::json!({"tags": {"env": null}}))
+            .unwrap();
+        let s = serde_json::to_string(&server).unwrap();
+        expect![[r#"{"port":9090,"cert":"a.pem","tags":{}}"#]].assert_eq(&s);
+
+        server.cert = None;
+        let s = server.merge_patch_from_default().to_string();
+        expect![[r#"{"cert":null,"port":9090}"#]].assert_eq(&s);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_patch_works() {
+        #[derive(SerdeDefault, Serialize, Deserialize)]
+        #[serde_default(json)]
+        struct Server {
+            #[default(8080)]
+            port: u16,
+            #[default(Some("a.pem".into()))]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            cert: Option<String>,
+            tags: std::collections::BTreeMap<String, String>,
+        }
+
+        let s = Server::default().json_patch_from_default().to_string();
+        expect![[r#"[]"#]].assert_eq(&s);
+
+        let mut server = Server {
+            port: 9090,
+            cert: None,
+            ..Server::default()
+        };
+        server.tags.insert("team/a~b".into(), "core".into());
+        let s = server.json_patch_from_default().to_string();
+        expect![[r#"[{"op":"remove","path":"/cert"},{"op":"replace","path":"/port","value":9090},{"op":"add","path":"/tags/team~1a~0b","value":"core"}]"#]].assert_eq(&s);
+    }
+
+    #[test]
+    fn env_default_works() {
+        #[serde_defaults]
+        #[derive(Serialize, Deserialize)]
+        struct Server {
+            #[serde_default(env = "SERDE_DEFAULT_UTILS_TEST_PORT", fallback = 8080)]
+            port: u16,
+            #[serde_default(env = "SERDE_DEFAULT_UTILS_TEST_HOST")]
+            #[default("0.0.0.0")]
+            host: String,
+            #[serde_default(env = "SERDE_DEFAULT_UTILS_TEST_WORKERS")]
+            workers: u8,
+        }
+
+        let config: Server = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":8080,"host":"0.0.0.0","workers":0}"#]].assert_eq(&s);
+
+        std::env::set_var("SERDE_DEFAULT_UTILS_TEST_PORT", "9090");
+        std::env::set_var("SERDE_DEFAULT_UTILS_TEST_WORKERS", "4");
+        let config: Server = serde_json::from_str(r#"{"port":1}"#).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":1,"host":"0.0.0.0","workers":4}"#]].assert_eq(&s);
+        assert_eq!(Server::default().port, 9090);
+
+        std::env::set_var("SERDE_DEFAULT_UTILS_TEST_WORKERS", "many");
+        assert!(std::panic::catch_unwind(Server::default_workers).is_err());
+        std::env::remove_var("SERDE_DEFAULT_UTILS_TEST_PORT");
+        std::env::remove_var("SERDE_DEFAULT_UTILS_TEST_WORKERS");
+    }
+
+    #[test]
+    fn from_env_works() {
+        #[serde_defaults(env_prefix = "")]
+        #[derive(Serialize, Deserialize)]
+        struct Tls {
+            enabled: bool,
+            #[default("a.pem")]
+            cert: String,
+        }
+
+        #[serde_defaults(env_prefix = "SERDE_DEFAULT_UTILS_APP_")]
+        #[derive(Serialize, Deserialize)]
+        struct App {
+            #[default(8080)]
+            port: u16,
+            #[serde_default(nested)]
+            tls: Tls,
+            #[serde_default(skip_env)]
+            tags: Vec<String>,
+        }
+
+        let s = serde_json::to_string(&App::from_env().unwrap()).unwrap();
+        expect![[r#"{"port":8080,"tls":{"enabled":false,"cert":"a.pem"},"tags":[]}"#]]
+            .assert_eq(&s);
+
+        std::env::set_var("SERDE_DEFAULT_UTILS_APP_PORT", "9090");
+        std::env::set_var("SERDE_DEFAULT_UTILS_APP_TLS__ENABLED", "true");
+        let s = serde_json::to_string(&App::from_env().unwrap()).unwrap();
+        expect![[r#"{"port":9090,"tls":{"enabled":true,"cert":"a.pem"},"tags":[]}"#]].assert_eq(&s);
+
+        std::env::set_var("SERDE_DEFAULT_UTILS_APP_TLS__ENABLED", "yes");
+        let error = App::from_env().err().unwrap();
+        assert_eq!(error.var(), "SERDE_DEFAULT_UTILS_APP_TLS__ENABLED");
+        assert_eq!(error.value(), Some("yes"));
+        std::env::remove_var("SERDE_DEFAULT_UTILS_APP_PORT");
+        std::env::remove_var("SERDE_DEFAULT_UTILS_APP_TLS__ENABLED");
+    }
+
+    #[cfg(feature = "file")]
+    #[test]
+    fn defaults_file_works() {
+        #[serde_defaults]
+        #[derive(Serialize, Deserialize)]
+        struct Tls {
+            enabled: bool,
+            cert: String,
         }
+
+        #[serde_defaults(file = "testdata/defaults.toml")]
+        #[derive(Serialize, Deserialize)]
+        struct Server {
+            #[default(9090)]
+            port: u16,
+            host: &'static str,
+            ratio: f64,
+            offset: i64,
+            tags: Vec<String>,
+            tls: Tls,
+            verbose: bool,
+        }
+
+        let config: Server = serde_json::from_str(EMPTY_JSON).unwrap();
+        let s = serde_json::to_string(&config).unwrap();
+        expect![[r#"{"port":9090,"host":"0.0.0.0","ratio":0.5,"offset":-3,"tags":["web","eu"],"tls":{"enabled":true,"cert":"a.pem"},"verbose":false}"#]].assert_eq(&s);
     }
 }
@@ -0,0 +1,20 @@
+//! Conversion of literals into the declared type of a default
+
+/// Converts a literal into the declared type, `&'static str` into `String` and anything else as is,
+/// unlike `Into` this keeps `10` inferred as the target integer
+pub trait LitInto<T> {
+    fn lit_into(self) -> T;
+}
+
+impl<T> LitInto<T> for T {
+    fn lit_into(self) -> T {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl LitInto<alloc::string::String> for &'static str {
+    fn lit_into(self) -> alloc::string::String {
+        alloc::string::String::from(self)
+    }
+}
@@ -0,0 +1,131 @@
+//! Minimal money type behind the `money` arm of [`serde_default`](crate::serde_default)
+use core::fmt;
+use core::str::FromStr;
+
+/// Amount with an ISO 4217 currency code, (de)serialized as `"19.99 USD"`
+///
+/// The amount keeps the precision it was written with, `19.99` is `1999` with a scale of `2`
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// let price: Money = "19.99 USD".parse().unwrap();
+/// assert_eq!(price.amount(), 1999);
+/// assert_eq!(price.scale(), 2);
+/// assert_eq!(price.currency(), "USD");
+/// assert_eq!(price.to_string(), "19.99 USD");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: i64,
+    scale: u32,
+    currency: [u8; 3],
+}
+
+impl Money {
+    /// Parses `"19.99 USD"`, the code has to be three uppercase letters
+    pub const fn parse(text: &str) -> Option<Self> {
+        let bytes = text.as_bytes();
+        if bytes.len() < 5 {
+            return None;
+        }
+        let (amount, code) = bytes.split_at(bytes.len() - 4);
+        let currency = [code[1], code[2], code[3]];
+        if code[0] != b' '
+            || !currency[0].is_ascii_uppercase()
+            || !currency[1].is_ascii_uppercase()
+            || !currency[2].is_ascii_uppercase()
+        {
+            return None;
+        }
+        let Ok(amount) = core::str::from_utf8(amount) else {
+            return None;
+        };
+        match crate::num::parse_decimal(amount) {
+            Some((amount, scale))
+                if amount >= i64::MIN as i128 && amount <= i64::MAX as i128 && scale <= 18 =>
+            {
+                Some(Self {
+                    amount: amount as i64,
+                    scale,
+                    currency,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Amount in units of `10^-scale`
+    pub const fn amount(&self) -> i64 {
+        self.amount
+    }
+
+    /// Number of decimal places the amount was written with
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// ISO 4217 code like `USD`
+    pub const fn currency(&self) -> &str {
+        match core::str::from_utf8(&self.currency) {
+            Ok(currency) => currency,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let divisor = 10u64.pow(self.scale);
+        let abs = self.amount.unsigned_abs();
+        let sign = if self.amount < 0 { "-" } else { "" };
+        write!(f, "{sign}{}", abs / divisor)?;
+        if self.scale > 0 {
+            write!(f, ".{:0width$}", abs % divisor, width = self.scale as usize)?;
+        }
+        write!(f, " {}", self.currency())
+    }
+}
+
+/// Error returned when a string isn't an amount followed by a currency code
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseMoneyError;
+
+impl fmt::Display for ParseMoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected an amount and a currency code like `19.99 USD`")
+    }
+}
+
+impl FromStr for Money {
+    type Err = ParseMoneyError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text).ok_or(ParseMoneyError)
+    }
+}
+
+impl serde::Serialize for Money {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Money {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MoneyVisitor;
+
+        impl serde::de::Visitor<'_> for MoneyVisitor {
+            type Value = Money;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an amount and a currency code like `19.99 USD`")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<Money, E> {
+                text.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(MoneyVisitor)
+    }
+}
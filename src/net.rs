@@ -0,0 +1,240 @@
+//! `const` parsers behind the `ip`, `ipv4`, `ipv6`, `socket` and `cidr` arms of [`serde_default`](crate::serde_default),
+//! they follow the same rules as `FromStr` in `std`
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+pub const fn parse_ip(text: &str) -> Option<IpAddr> {
+    if let Some(ip) = parse_ipv4(text) {
+        return Some(IpAddr::V4(ip));
+    }
+    match parse_ipv6(text) {
+        Some(ip) => Some(IpAddr::V6(ip)),
+        None => None,
+    }
+}
+
+pub const fn parse_ipv4(text: &str) -> Option<Ipv4Addr> {
+    let bytes = text.as_bytes();
+    parse_ipv4_bytes(bytes, 0, bytes.len())
+}
+
+pub const fn parse_ipv6(text: &str) -> Option<Ipv6Addr> {
+    let bytes = text.as_bytes();
+    parse_ipv6_bytes(bytes, 0, bytes.len())
+}
+
+/// `1.2.3.4:80` or `[::1]:80`
+pub const fn parse_socket_addr(text: &str) -> Option<SocketAddr> {
+    let bytes = text.as_bytes();
+    let end = bytes.len();
+    if end > 0 && bytes[0] == b'[' {
+        let mut close = 1;
+        while close < end && bytes[close] != b']' {
+            close += 1;
+        }
+        if close + 1 >= end || bytes[close + 1] != b':' {
+            return None;
+        }
+        return match (
+            parse_ipv6_bytes(bytes, 1, close),
+            parse_u16(bytes, close + 2, end),
+        ) {
+            (Some(ip), Some(port)) => Some(SocketAddr::new(IpAddr::V6(ip), port)),
+            _ => None,
+        };
+    }
+    let mut colon = end;
+    while colon > 0 {
+        colon -= 1;
+        if bytes[colon] == b':' {
+            return match (
+                parse_ipv4_bytes(bytes, 0, colon),
+                parse_u16(bytes, colon + 1, end),
+            ) {
+                (Some(ip), Some(port)) => Some(SocketAddr::new(IpAddr::V4(ip), port)),
+                _ => None,
+            };
+        }
+    }
+    None
+}
+
+/// `10.0.0.0/8` or `fd00::/8`, host bits are allowed just like in `ipnet`
+#[cfg(feature = "ipnet")]
+pub const fn parse_ipnet(text: &str) -> Option<ipnet::IpNet> {
+    let bytes = text.as_bytes();
+    let end = bytes.len();
+    let mut slash = 0;
+    while slash < end && bytes[slash] != b'/' {
+        slash += 1;
+    }
+    let prefix = match parse_u16(bytes, slash + 1, end) {
+        Some(prefix) if prefix <= u8::MAX as u16 => prefix as u8,
+        _ => return None,
+    };
+    if let Some(ip) = parse_ipv4_bytes(bytes, 0, slash) {
+        return match ipnet::Ipv4Net::new(ip, prefix) {
+            Ok(net) => Some(ipnet::IpNet::V4(net)),
+            Err(_) => None,
+        };
+    }
+    match parse_ipv6_bytes(bytes, 0, slash) {
+        Some(ip) => match ipnet::Ipv6Net::new(ip, prefix) {
+            Ok(net) => Some(ipnet::IpNet::V6(net)),
+            Err(_) => None,
+        },
+        None => None,
+    }
+}
+
+const fn parse_u16(bytes: &[u8], mut i: usize, end: usize) -> Option<u16> {
+    if i >= end {
+        return None;
+    }
+    let mut value: u32 = 0;
+    while i < end {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (bytes[i] - b'0') as u32;
+        if value > u16::MAX as u32 {
+            return None;
+        }
+        i += 1;
+    }
+    Some(value as u16)
+}
+
+const fn parse_ipv4_bytes(bytes: &[u8], mut i: usize, end: usize) -> Option<Ipv4Addr> {
+    let mut octets = [0u8; 4];
+    let mut n = 0;
+    while n < 4 {
+        let start = i;
+        let mut value: u16 = 0;
+        while i < end && bytes[i].is_ascii_digit() {
+            if i - start == 3 {
+                return None;
+            }
+            value = value * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+        let digits = i - start;
+        // leading zeros are rejected by std to avoid octal confusion
+        if digits == 0 || value > 255 || (digits > 1 && bytes[start] == b'0') {
+            return None;
+        }
+        octets[n] = value as u8;
+        n += 1;
+        if n < 4 {
+            if i >= end || bytes[i] != b'.' {
+                return None;
+            }
+            i += 1;
+        }
+    }
+    if i != end {
+        return None;
+    }
+    Some(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]))
+}
+
+const fn parse_ipv6_bytes(bytes: &[u8], mut i: usize, end: usize) -> Option<Ipv6Addr> {
+    let mut groups = [0u16; 8];
+    let mut count = 0;
+    // index of the group where `::` was found
+    let mut gap = None;
+    if end - i >= 2 && bytes[i] == b':' && bytes[i + 1] == b':' {
+        gap = Some(0);
+        i += 2;
+    } else if i < end && bytes[i] == b':' {
+        return None;
+    }
+    while i < end {
+        if count == 8 {
+            return None;
+        }
+        // an embedded ipv4 address takes the last two groups
+        if group_has_dot(bytes, i, end) {
+            if count > 6 {
+                return None;
+            }
+            let octets = match parse_ipv4_bytes(bytes, i, end) {
+                Some(ip) => ip.octets(),
+                None => return None,
+            };
+            groups[count] = (octets[0] as u16) << 8 | octets[1] as u16;
+            groups[count + 1] = (octets[2] as u16) << 8 | octets[3] as u16;
+            count += 2;
+            break;
+        }
+        let start = i;
+        let mut value: u16 = 0;
+        while i < end {
+            let digit = match hex_digit(bytes[i]) {
+                Some(digit) => digit,
+                None => break,
+            };
+            if i - start == 4 {
+                return None;
+            }
+            value = value << 4 | digit as u16;
+            i += 1;
+        }
+        if i == start {
+            return None;
+        }
+        groups[count] = value;
+        count += 1;
+        if i < end {
+            if bytes[i] != b':' {
+                return None;
+            }
+            i += 1;
+            if i < end && bytes[i] == b':' {
+                if gap.is_some() {
+                    return None;
+                }
+                gap = Some(count);
+                i += 1;
+            } else if i == end {
+                return None;
+            }
+        }
+    }
+    match gap {
+        // `::` has to stand for at least one group
+        Some(_) if count == 8 => return None,
+        Some(at) => {
+            let shift = 8 - count;
+            let mut j = count;
+            while j > at {
+                j -= 1;
+                groups[j + shift] = groups[j];
+                groups[j] = 0;
+            }
+        }
+        None if count != 8 => return None,
+        None => {}
+    }
+    Some(Ipv6Addr::new(
+        groups[0], groups[1], groups[2], groups[3], groups[4], groups[5], groups[6], groups[7],
+    ))
+}
+
+const fn group_has_dot(bytes: &[u8], mut i: usize, end: usize) -> bool {
+    while i < end && bytes[i] != b':' {
+        if bytes[i] == b'.' {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+pub(crate) const fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
@@ -0,0 +1,294 @@
+//! `const` number parsers behind the numeric arms of [`serde_default`](crate::serde_default)
+
+/// Splits `-12.345` into mantissa `-12345` and scale `3`, `_` separators are skipped
+pub const fn parse_decimal(text: &str) -> Option<(i128, u32)> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    let negative = match bytes.first() {
+        Some(b'-') => {
+            i += 1;
+            true
+        }
+        Some(b'+') => {
+            i += 1;
+            false
+        }
+        _ => false,
+    };
+    let mut mantissa: i128 = 0;
+    let mut scale = 0;
+    let mut digits = 0;
+    let mut fraction = false;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                let digit = (bytes[i] - b'0') as i128;
+                mantissa = match mantissa.checked_mul(10) {
+                    Some(value) => match value.checked_add(digit) {
+                        Some(value) => value,
+                        None => return None,
+                    },
+                    None => return None,
+                };
+                digits += 1;
+                if fraction {
+                    scale += 1;
+                }
+            }
+            b'.' if !fraction => fraction = true,
+            b'_' => {}
+            _ => return None,
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        return None;
+    }
+    Some((if negative { -mantissa } else { mantissa }, scale))
+}
+
+/// `-1_000`, `0xff`, `0o644` or `0b1010` as a sign and a magnitude, `_` separators are skipped
+pub const fn parse_integer(text: &str) -> Option<(bool, u128)> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    let negative = match bytes.first() {
+        Some(b'-') => {
+            i += 1;
+            true
+        }
+        Some(b'+') => {
+            i += 1;
+            false
+        }
+        _ => false,
+    };
+    let radix = match bytes.split_at(i).1 {
+        [b'0', b'x' | b'X', ..] => 16,
+        [b'0', b'o' | b'O', ..] => 8,
+        [b'0', b'b' | b'B', ..] => 2,
+        _ => 10,
+    };
+    if radix != 10 {
+        i += 2;
+    }
+    let mut value: u128 = 0;
+    let mut digits = 0;
+    while i < bytes.len() {
+        let digit = match bytes[i] {
+            b'_' => {
+                i += 1;
+                continue;
+            }
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => return None,
+        };
+        if digit as u32 >= radix {
+            return None;
+        }
+        value = match value.checked_mul(radix as u128) {
+            Some(value) => match value.checked_add(digit as u128) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        digits += 1;
+        i += 1;
+    }
+    if digits == 0 {
+        return None;
+    }
+    Some((negative, value))
+}
+
+/// `0.015` as a `rust_decimal::Decimal`, `None` past 96 bits of mantissa or 28 digits of scale
+#[cfg(feature = "decimal")]
+pub const fn parse_rust_decimal(text: &str) -> Option<rust_decimal::Decimal> {
+    let (mantissa, scale) = match parse_decimal(text) {
+        Some(parts) => parts,
+        None => return None,
+    };
+    let abs = mantissa.unsigned_abs();
+    if abs >> 96 != 0 || scale > rust_decimal::Decimal::MAX_SCALE {
+        return None;
+    }
+    Some(rust_decimal::Decimal::from_parts(
+        abs as u32,
+        (abs >> 32) as u32,
+        (abs >> 64) as u32,
+        mantissa < 0,
+        scale,
+    ))
+}
+
+/// `-3/4` or `7` as a reduced `Ratio<i64>` with a positive denominator, just like `Ratio::new`
+#[cfg(feature = "num-rational")]
+pub const fn parse_ratio(text: &str) -> Option<num_rational::Ratio<i64>> {
+    let bytes = text.as_bytes();
+    let end = bytes.len();
+    let mut slash = 0;
+    while slash < end && bytes[slash] != b'/' {
+        slash += 1;
+    }
+    let numer = match parse_int(bytes, 0, slash) {
+        Some(numer) => numer,
+        None => return None,
+    };
+    let denom = if slash == end {
+        1
+    } else {
+        match parse_int(bytes, slash + 1, end) {
+            Some(0) | None => return None,
+            Some(denom) => denom,
+        }
+    };
+    let (mut a, mut b) = (numer.unsigned_abs(), denom.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    let (mut numer, mut denom) = (numer / a as i128, denom / a as i128);
+    if denom < 0 {
+        (numer, denom) = (-numer, -denom);
+    }
+    if numer < i64::MIN as i128 || numer > i64::MAX as i128 || denom > i64::MAX as i128 {
+        return None;
+    }
+    Some(num_rational::Ratio::new_raw(numer as i64, denom as i64))
+}
+
+/// Optionally signed integer in `bytes[i..end]`, `_` separators are skipped
+#[cfg(feature = "num-rational")]
+const fn parse_int(bytes: &[u8], mut i: usize, end: usize) -> Option<i128> {
+    let negative = i < end && bytes[i] == b'-';
+    if i < end && (bytes[i] == b'-' || bytes[i] == b'+') {
+        i += 1;
+    }
+    let mut value: i128 = 0;
+    let mut digits = 0;
+    while i < end {
+        match bytes[i] {
+            b'0'..=b'9' => {
+                value = match value.checked_mul(10) {
+                    Some(value) => match value.checked_add((bytes[i] - b'0') as i128) {
+                        Some(value) => value,
+                        None => return None,
+                    },
+                    None => return None,
+                };
+                digits += 1;
+            }
+            b'_' => {}
+            _ => return None,
+        }
+        i += 1;
+    }
+    if digits == 0 {
+        return None;
+    }
+    Some(if negative { -value } else { value })
+}
+
+/// `10MiB`, `1.5 GB` or `512` as a number of bytes, units are case insensitive,
+/// `K`/`KB` are powers of 1000 and `Ki`/`KiB` powers of 1024
+pub const fn parse_byte_size(text: &str) -> Option<u64> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    let mut whole: u64 = 0;
+    let mut digits = 0;
+    while i < bytes.len() && bytes[i].is_ascii_digit() {
+        whole = match whole.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[i] - b'0') as u64) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        digits += 1;
+        i += 1;
+    }
+    // fraction digits past 18 can't change the result of any unit up to EiB
+    let mut fraction: u64 = 0;
+    let mut scale: u64 = 1;
+    if i < bytes.len() && bytes[i] == b'.' {
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            if scale < 1_000_000_000_000_000_000 {
+                fraction = fraction * 10 + (bytes[i] - b'0') as u64;
+                scale *= 10;
+            }
+            digits += 1;
+            i += 1;
+        }
+    }
+    if digits == 0 {
+        return None;
+    }
+    while i < bytes.len() && bytes[i] == b' ' {
+        i += 1;
+    }
+    let unit = match unit_multiplier(bytes, i) {
+        Some(unit) => unit,
+        None => return None,
+    };
+    let fraction = (fraction as u128 * unit as u128 / scale as u128) as u64;
+    match whole.checked_mul(unit) {
+        Some(value) => value.checked_add(fraction),
+        None => None,
+    }
+}
+
+const fn unit_multiplier(bytes: &[u8], i: usize) -> Option<u64> {
+    let rest = bytes.len() - i;
+    if rest == 0 || (rest == 1 && bytes[i].eq_ignore_ascii_case(&b'b')) {
+        return Some(1);
+    }
+    let power = match bytes[i].to_ascii_lowercase() {
+        b'k' => 1,
+        b'm' => 2,
+        b'g' => 3,
+        b't' => 4,
+        b'p' => 5,
+        b'e' => 6,
+        _ => return None,
+    };
+    let base: u64 = match rest {
+        1 => 1000,
+        2 if bytes[i + 1].eq_ignore_ascii_case(&b'b') => 1000,
+        2 if bytes[i + 1].eq_ignore_ascii_case(&b'i') => 1024,
+        3 if bytes[i + 1].eq_ignore_ascii_case(&b'i')
+            && bytes[i + 2].eq_ignore_ascii_case(&b'b') =>
+        {
+            1024
+        }
+        _ => return None,
+    };
+    Some(base.pow(power))
+}
+
+/// `2.5%` as `0.025`, or as `2.5` with `points`, the `%` is optional and has to be in `0..=100`
+pub const fn parse_percent(text: &str, points: bool) -> Option<f64> {
+    let bytes = text.as_bytes();
+    let text = match bytes.split_last() {
+        Some((b'%', rest)) => match core::str::from_utf8(rest) {
+            Ok(rest) => rest,
+            Err(_) => return None,
+        },
+        _ => text,
+    };
+    let (mantissa, scale) = match parse_decimal(text) {
+        Some(parts) => parts,
+        None => return None,
+    };
+    let scale = if points { scale } else { scale + 2 };
+    // one division of two exact integers keeps `2.5%` exactly `0.025`
+    let divisor = match 10u128.checked_pow(scale) {
+        Some(divisor) if divisor <= 1 << f64::MANTISSA_DIGITS => divisor,
+        _ => return None,
+    };
+    if mantissa < 0 || mantissa as u128 > divisor * if points { 100 } else { 1 } {
+        return None;
+    }
+    Some(mantissa as f64 / divisor as f64)
+}
@@ -0,0 +1,59 @@
+//! Filesystem path defaults
+use std::path::PathBuf;
+
+/// Per-user config directory, e.g. `~/.config` on Linux
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// let dir: Option<std::path::PathBuf> = default_config_dir();
+/// ```
+#[cfg(feature = "dirs")]
+pub fn default_config_dir() -> Option<PathBuf> {
+    dirs::config_dir()
+}
+
+/// Per-user cache directory, e.g. `~/.cache` on Linux
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// let dir: Option<std::path::PathBuf> = default_cache_dir();
+/// ```
+#[cfg(feature = "dirs")]
+pub fn default_cache_dir() -> Option<PathBuf> {
+    dirs::cache_dir()
+}
+
+/// Per-user data directory, e.g. `~/.local/share` on Linux
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// let dir: Option<std::path::PathBuf> = default_data_dir();
+/// ```
+#[cfg(feature = "dirs")]
+pub fn default_data_dir() -> Option<PathBuf> {
+    dirs::data_dir()
+}
+
+/// Working directory of the process, `.` if it can't be determined
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// assert_eq!(default_current_dir(), std::env::current_dir().unwrap());
+/// ```
+pub fn default_current_dir() -> PathBuf {
+    std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
+}
+
+/// Directory holding the running executable, `.` if it can't be determined
+/// ```rust
+/// use serde_default_utils::*;
+///
+/// let exe = std::env::current_exe().unwrap();
+/// assert_eq!(default_exe_dir(), exe.parent().unwrap());
+/// ```
+pub fn default_exe_dir() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
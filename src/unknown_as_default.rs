@@ -0,0 +1,87 @@
+//! `deserialize_with` helper that turns unknown enum variants into the default one,
+//! so older binaries keep working when a new variant ships first
+//!
+//! Works for unit variants written as strings or as integer variant indices, anything else is still an error.
+//! The variant is read with `deserialize_any`, so this needs a self-describing format like JSON, TOML or YAML,
+//! formats that only encode the index like bincode or postcard reject it
+//! ```rust
+//! use serde::Deserialize;
+//! use serde_default_utils::*;
+//!
+//! #[derive(Deserialize, Default, Debug, PartialEq)]
+//! enum Tier {
+//!     #[default]
+//!     Free,
+//!     Pro,
+//! }
+//!
+//! #[derive(Deserialize)]
+//! struct Account {
+//!     #[serde(deserialize_with = "unknown_as_default::deserialize")]
+//!     tier: Tier,
+//! }
+//!
+//! let account: Account = serde_json::from_str(r#"{"tier":"Enterprise"}"#).unwrap();
+//! assert_eq!(account.tier, Tier::Free);
+//! let account: Account = serde_json::from_str(r#"{"tier":"Pro"}"#).unwrap();
+//! assert_eq!(account.tier, Tier::Pro);
+//! let account: Account = serde_json::from_str(r#"{"tier":1}"#).unwrap();
+//! assert_eq!(account.tier, Tier::Pro);
+//! ```
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+
+/// Deserializes `T`, falling back to `T::default()` when the variant isn't known,
+/// the format has to support `deserialize_any`
+pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: de::Deserializer<'de>,
+    T: DeserializeOwned + Default,
+{
+    deserializer.deserialize_any(VariantVisitor(PhantomData))
+}
+
+struct VariantVisitor<T>(PhantomData<T>);
+
+impl<T> VariantVisitor<T>
+where
+    T: DeserializeOwned + Default,
+{
+    fn variant<'a, V>(value: V) -> T
+    where
+        V: IntoDeserializer<'a, de::value::Error>,
+    {
+        T::deserialize(value.into_deserializer()).unwrap_or_default()
+    }
+}
+
+impl<T> Visitor<'_> for VariantVisitor<T>
+where
+    T: DeserializeOwned + Default,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an enum variant")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        Ok(Self::variant(value))
+    }
+
+    // only the `u32` deserializer can drive `deserialize_enum`, with the integer as the variant index
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<T, E> {
+        match u32::try_from(value) {
+            Ok(index) => Ok(Self::variant(index)),
+            Err(_) => Ok(Self::variant(value)),
+        }
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<T, E> {
+        match u32::try_from(value) {
+            Ok(index) => Ok(Self::variant(index)),
+            Err(_) => Ok(Self::variant(value)),
+        }
+    }
+}